@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
+
+use hound::WavReader;
+
+use crate::{Error, Segmenter, SpeechSegment, VoiceActivityDetector, WavLabels, WavOptions};
+
+/// Options for processing a directory of audio files.
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct BatchOptions {
+    /// The JSONL manifest to append results to. Files already processed
+    /// successfully are skipped, so an interrupted batch can be resumed.
+    #[builder(setter(into))]
+    pub manifest: PathBuf,
+    /// The options used to label each file.
+    #[builder(default)]
+    pub wav: WavOptions,
+    /// The number of worker threads. Defaults to the available parallelism.
+    #[builder(default, setter(strip_option))]
+    pub workers: Option<usize>,
+    /// The file extensions to process, compared case-insensitively.
+    #[builder(default = vec!["wav".into()])]
+    pub extensions: Vec<String>,
+}
+
+/// A single line of the batch manifest.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ManifestEntry {
+    /// The path of the audio file, relative to the processed directory.
+    pub path: PathBuf,
+    /// The duration of the audio in seconds.
+    pub duration: f64,
+    /// The fraction of the audio labeled as speech.
+    pub speech_ratio: f64,
+    /// The speech segments in the audio.
+    pub segments: Vec<SpeechSegment>,
+    /// The error that stopped the file from being processed, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A summary of a finished batch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// The number of files processed successfully.
+    pub processed: usize,
+    /// The number of files that failed to process.
+    pub failed: usize,
+    /// The number of files skipped because the manifest already has a
+    /// successful entry for them.
+    pub skipped: usize,
+}
+
+/// Labels every matching audio file in a directory, in parallel.
+///
+/// Each worker thread owns its own [VoiceActivityDetector], and every detector
+/// shares the default ONNX session. Results are appended to the manifest as each
+/// file finishes, one JSON object per line. Files that fail to process are
+/// recorded in the manifest with their error rather than stopping the batch.
+pub fn process_directory(
+    dir: impl AsRef<Path>,
+    options: &BatchOptions,
+) -> Result<BatchSummary, Error> {
+    let dir = dir.as_ref();
+    let (done, complete) = read_manifest(&options.manifest)?;
+    let mut files = Vec::new();
+    find_files(dir, &options.extensions, &mut files)?;
+    files.sort();
+
+    let mut summary = BatchSummary::default();
+    let before = files.len();
+    files.retain(|path| !done.contains(relative_path(dir, path)));
+    summary.skipped = before - files.len();
+
+    let mut manifest = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&options.manifest)?;
+    if !complete {
+        writeln!(manifest)?;
+    }
+
+    let workers = options
+        .workers
+        .or_else(|| std::thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1)
+        .clamp(1, files.len().max(1));
+    let queue = Mutex::new(files.into_iter());
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || {
+                let mut vad = None;
+                // Take the next file in a closure so the queue is unlocked while it is processed.
+                let next = || queue.lock().ok().and_then(|mut files| files.next());
+                while let Some(path) = next() {
+                    let mut entry = process_file(&path, &options.wav, &mut vad);
+                    entry.path = relative_path(dir, &path).to_path_buf();
+                    if sender.send(entry).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        for entry in receiver {
+            if entry.error.is_some() {
+                summary.failed += 1;
+            } else {
+                summary.processed += 1;
+            }
+            serde_json::to_writer(&mut manifest, &entry).map_err(std::io::Error::from)?;
+            writeln!(manifest)?;
+            manifest.flush()?;
+        }
+
+        Ok::<_, Error>(())
+    })?;
+
+    Ok(summary)
+}
+
+/// Labels a single file, reusing the worker's detector when its configuration matches.
+fn process_file(
+    path: &Path,
+    options: &WavOptions,
+    vad: &mut Option<VoiceActivityDetector>,
+) -> ManifestEntry {
+    let mut entry = ManifestEntry {
+        path: path.to_path_buf(),
+        duration: 0.0,
+        speech_ratio: 0.0,
+        segments: Vec::new(),
+        error: None,
+    };
+
+    let result = (|| {
+        let reader = WavReader::open(path)?;
+        let spec = reader.spec();
+        let reusable = vad.take().filter(|vad| {
+            vad.sample_rate() == i64::from(spec.sample_rate)
+                && vad.chunk_size() == options.chunk_size_for(spec.sample_rate)
+        });
+        let detector = match reusable {
+            Some(mut detector) => {
+                detector.reset();
+                detector
+            }
+            None => options.vad(spec)?,
+        };
+
+        let mut labels = WavLabels::with_vad(reader, detector, options);
+        let mut segmenter = Segmenter::new(spec.sample_rate);
+        for audio in labels.by_ref() {
+            if let Some(segment) = segmenter.push(&audio?) {
+                entry.segments.push(segment);
+            }
+        }
+        entry.segments.extend(segmenter.finish());
+        *vad = Some(labels.into_vad());
+
+        entry.duration = segmenter.position() as f64 / f64::from(spec.sample_rate);
+        Ok::<_, Error>(())
+    })();
+
+    match result {
+        Ok(()) if entry.duration > 0.0 => {
+            let speech: f64 = entry.segments.iter().map(SpeechSegment::duration).sum();
+            entry.speech_ratio = speech / entry.duration;
+        }
+        Ok(()) => {}
+        Err(error) => entry.error = Some(error.to_string()),
+    }
+
+    entry
+}
+
+/// Reads the paths already processed successfully, and whether the manifest's
+/// last line is complete. Lines that cannot be parsed, such as a line cut short
+/// by an interrupted batch, are ignored. Files that failed are not included, so
+/// they are retried.
+fn read_manifest(path: &Path) -> Result<(HashSet<PathBuf>, bool), Error> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok((HashSet::new(), true))
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut reader = BufReader::new(file);
+    let mut paths = HashSet::new();
+    let mut line = String::new();
+    let mut complete = true;
+    while reader.read_line(&mut line)? > 0 {
+        complete = line.ends_with('\n');
+        match serde_json::from_str::<ManifestEntry>(&line) {
+            Ok(entry) if entry.error.is_none() => {
+                paths.insert(entry.path);
+            }
+            _ => {}
+        }
+        line.clear();
+    }
+    Ok((paths, complete))
+}
+
+/// Returns the path of a file found in the directory, relative to it.
+fn relative_path<'a>(dir: &Path, path: &'a Path) -> &'a Path {
+    path.strip_prefix(dir).unwrap_or(path)
+}
+
+/// Recursively finds the files in a directory with one of the extensions.
+fn find_files(dir: &Path, extensions: &[String], files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_files(&path, extensions, files)?;
+            continue;
+        }
+
+        let matches = path.extension().is_some_and(|extension| {
+            extensions
+                .iter()
+                .any(|wanted| extension.eq_ignore_ascii_case(wanted.as_str()))
+        });
+        if matches {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,312 @@
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::channel::oneshot;
+use ndarray::{s, Array2, Array3, Ix3};
+use ort::session::Session;
+
+use crate::vad::DetectorConfig;
+use crate::{Error, Sample};
+
+/// Runs the chunks of many concurrent streams through the model in batches.
+///
+/// Each [BatchedVadHandle] keeps the state of one stream. Chunks predicted on
+/// any handle are collected by an inference thread and run in a single model
+/// call, with up to `max_batch_size` chunks stacked along the batch dimension.
+/// The thread waits at most `max_wait` for a batch to fill once the first chunk
+/// arrives. All streams share the sample rate and chunk size of the service.
+///
+/// ```rust,no_run
+/// fn main() -> Result<(), voice_activity_detector::Error> {
+///     use voice_activity_detector::BatchedVad;
+///
+///     let service = BatchedVad::builder()
+///         .sample_rate(8000)
+///         .chunk_size(256usize)
+///         .max_batch_size(128usize)
+///         .build()?;
+///
+///     let calls: Vec<_> = (0..4)
+///         .map(|_| {
+///             let mut vad = service.handle();
+///             std::thread::spawn(move || vad.predict([0i16; 256]))
+///         })
+///         .collect();
+///     for call in calls {
+///         println!("probability: {}", call.join().unwrap()?);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BatchedVad {
+    requests: mpsc::Sender<Request>,
+    chunk_size: usize,
+    sample_rate: i64,
+}
+
+/// The state of one stream predicted by a [BatchedVad].
+///
+/// The handle predicts chunks like a
+/// [VoiceActivityDetector](crate::VoiceActivityDetector), but returns
+/// [Error::Disconnected] if the inference thread has stopped. It stays usable
+/// after the service is dropped, as the inference thread runs until every
+/// handle is gone.
+///
+/// Handles implement [Predictor](crate::Predictor), so they can be passed to
+/// the iterator and stream adapters by value or by mutable reference.
+///
+/// ```rust,no_run
+/// fn main() -> Result<(), voice_activity_detector::Error> {
+///     use voice_activity_detector::{BatchedVad, IteratorExt};
+///
+///     let service = BatchedVad::builder()
+///         .sample_rate(8000)
+///         .chunk_size(256usize)
+///         .build()?;
+///     let mut vad = service.handle();
+///
+///     let samples = vec![0i16; 2560];
+///     let speech = samples.into_iter().label(&mut vad, 0.5, 3).filter(|label| label.is_speech()).count();
+///     println!("speech chunks: {speech}");
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BatchedVadHandle {
+    requests: mpsc::Sender<Request>,
+    chunk_size: usize,
+    sample_rate: i64,
+    h: Array3<f32>,
+    c: Array3<f32>,
+}
+
+/// A chunk waiting to be predicted, with the state of its stream.
+struct Request {
+    input: Vec<f32>,
+    h: Array3<f32>,
+    c: Array3<f32>,
+    reply: oneshot::Sender<Reply>,
+}
+
+/// The probability of a chunk and the next state of its stream.
+struct Reply {
+    probability: f32,
+    h: Array3<f32>,
+    c: Array3<f32>,
+}
+
+impl BatchedVad {
+    /// Create a new [BatchedVadBuilder].
+    pub fn builder() -> BatchedVadBuilder {
+        BatchedVadConfig::builder()
+    }
+
+    /// Creates a handle for a new stream, starting from a reset state.
+    pub fn handle(&self) -> BatchedVadHandle {
+        BatchedVadHandle {
+            requests: self.requests.clone(),
+            chunk_size: self.chunk_size,
+            sample_rate: self.sample_rate,
+            h: Array3::zeros((2, 1, 64)),
+            c: Array3::zeros((2, 1, 64)),
+        }
+    }
+
+    /// Gets the chunk size
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Gets the sample rate
+    pub fn sample_rate(&self) -> i64 {
+        self.sample_rate
+    }
+}
+
+impl BatchedVadHandle {
+    /// Gets the chunk size
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Gets the sample rate
+    pub fn sample_rate(&self) -> i64 {
+        self.sample_rate
+    }
+
+    /// Resets the state of the stream.
+    pub fn reset(&mut self) {
+        self.h.fill(0f32);
+        self.c.fill(0f32);
+    }
+
+    /// Predicts the existence of speech in a single iterable of audio, blocking
+    /// until its batch has run.
+    ///
+    /// The samples iterator will be padded if it is too short, or truncated if it is
+    /// too long.
+    pub fn predict<S, I>(&mut self, samples: I) -> Result<f32, Error>
+    where
+        S: Sample,
+        I: IntoIterator<Item = S>,
+    {
+        futures::executor::block_on(self.predict_async(samples))
+    }
+
+    /// Predicts the existence of speech in a single iterable of audio, waiting
+    /// for its batch without blocking the executor.
+    ///
+    /// The samples iterator will be padded if it is too short, or truncated if it is
+    /// too long.
+    pub async fn predict_async<S, I>(&mut self, samples: I) -> Result<f32, Error>
+    where
+        S: Sample,
+        I: IntoIterator<Item = S>,
+    {
+        let mut input: Vec<f32> = samples
+            .into_iter()
+            .take(self.chunk_size)
+            .map(Sample::to_f32)
+            .collect();
+        input.resize(self.chunk_size, 0f32);
+
+        let (reply, response) = oneshot::channel();
+        let request = Request {
+            input,
+            h: self.h.clone(),
+            c: self.c.clone(),
+            reply,
+        };
+        self.requests
+            .send(request)
+            .map_err(|_| Error::Disconnected)?;
+        let response = response.await.map_err(|_| Error::Disconnected)?;
+
+        self.h = response.h;
+        self.c = response.c;
+        Ok(response.probability)
+    }
+}
+
+/// Collects requests into batches until every sender is dropped.
+fn run(
+    session: Arc<Session>,
+    requests: mpsc::Receiver<Request>,
+    sample_rate: i64,
+    max_batch_size: usize,
+    max_wait: Duration,
+) {
+    while let Ok(first) = requests.recv() {
+        let deadline = Instant::now() + max_wait;
+        let mut batch = vec![first];
+        while batch.len() < max_batch_size {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            match requests.recv_timeout(timeout) {
+                Ok(request) => batch.push(request),
+                Err(_) => break,
+            }
+        }
+
+        predict_batch(&session, sample_rate, batch);
+    }
+}
+
+/// Runs one model call over the stacked chunks and states of the batch.
+fn predict_batch(session: &Session, sample_rate: i64, batch: Vec<Request>) {
+    let size = batch.len();
+    let chunk_size = batch[0].input.len();
+    let mut input = Array2::<f32>::zeros((size, chunk_size));
+    let mut h = Array3::<f32>::zeros((2, size, 64));
+    let mut c = Array3::<f32>::zeros((2, size, 64));
+    for (i, request) in batch.iter().enumerate() {
+        input.row_mut(i).assign(&ndarray::aview1(&request.input));
+        h.slice_mut(s![.., i..i + 1, ..]).assign(&request.h);
+        c.slice_mut(s![.., i..i + 1, ..]).assign(&request.c);
+    }
+
+    let sample_rate = ndarray::arr1::<i64>(&[sample_rate]);
+
+    let inputs = ort::inputs![
+        "input" => input.view(),
+        "sr" => sample_rate.view(),
+        "h" => h.view(),
+        "c" => c.view(),
+    ]
+    .unwrap();
+
+    let outputs = session.run(inputs).unwrap();
+
+    let hn = outputs
+        .get("hn")
+        .unwrap()
+        .try_extract_tensor::<f32>()
+        .unwrap();
+    let hn = hn.view().into_dimensionality::<Ix3>().unwrap();
+    let cn = outputs
+        .get("cn")
+        .unwrap()
+        .try_extract_tensor::<f32>()
+        .unwrap();
+    let cn = cn.view().into_dimensionality::<Ix3>().unwrap();
+    let output = outputs
+        .get("output")
+        .unwrap()
+        .try_extract_tensor::<f32>()
+        .unwrap();
+
+    // Scatter the probabilities and next states back to each stream.
+    for (i, request) in batch.into_iter().enumerate() {
+        let reply = Reply {
+            probability: output.view()[[i, 0]],
+            h: hn.slice(s![.., i..i + 1, ..]).to_owned(),
+            c: cn.slice(s![.., i..i + 1, ..]).to_owned(),
+        };
+        // The stream may have been dropped while waiting.
+        let _ = request.reply.send(reply);
+    }
+}
+
+/// The configuration for the [BatchedVad]. Used to create a
+/// [BatchedVadBuilder] that performs runtime validation on build.
+#[derive(Debug, typed_builder::TypedBuilder)]
+#[builder(
+    builder_method(vis = ""),
+    builder_type(name = BatchedVadBuilder, vis = "pub"),
+    build_method(into = Result<BatchedVad, Error>, vis = "pub"))
+]
+struct BatchedVadConfig {
+    #[builder(setter(into))]
+    chunk_size: usize,
+    #[builder(setter(into))]
+    sample_rate: i64,
+    #[builder(default = 64, setter(into))]
+    max_batch_size: usize,
+    #[builder(default = Duration::from_millis(2))]
+    max_wait: Duration,
+    #[builder(default, setter(strip_option))]
+    session: Option<Arc<Session>>,
+}
+
+impl From<BatchedVadConfig> for Result<BatchedVad, Error> {
+    fn from(value: BatchedVadConfig) -> Self {
+        let DetectorConfig {
+            session,
+            sample_rate,
+            chunk_size,
+        } = DetectorConfig::new(value.sample_rate, value.chunk_size, value.session)?;
+        let (requests, receiver) = mpsc::channel();
+        let max_batch_size = value.max_batch_size.max(1);
+        let max_wait = value.max_wait;
+        std::thread::Builder::new()
+            .name("vad-batch".into())
+            .spawn(move || run(session, receiver, sample_rate, max_batch_size, max_wait))?;
+
+        Ok(BatchedVad {
+            requests,
+            chunk_size,
+            sample_rate,
+        })
+    }
+}
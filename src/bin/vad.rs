@@ -0,0 +1,358 @@
+//! Detects speech in WAV or raw PCM audio, printing the speech timestamps.
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, ValueEnum};
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+use voice_activity_detector::{
+    process_directory, write_audacity, write_rttm, write_srt, write_wav_samples, write_webvtt,
+    BatchOptions, Endianness, Error, IteratorExt, LabeledAudio, PcmFormat, PcmReader, Sample,
+    Segmenter, SpeechSegment, VoiceActivityDetector, WavLabels, WavOptions,
+};
+
+/// Detects speech in an audio file, printing the start and end of each speech segment in seconds.
+///
+/// When the input is a directory, every WAV file in it is processed in parallel
+/// and the results are appended to a JSONL manifest.
+#[derive(Debug, Parser)]
+#[command(name = "vad", version)]
+struct Args {
+    /// The audio file or directory to read, or `-` for stdin.
+    #[arg(default_value = "-")]
+    input: String,
+
+    /// Probabilities greater than or equal to this value are considered speech.
+    #[arg(short, long, default_value_t = 0.5, value_parser = parse_threshold)]
+    threshold: f32,
+
+    /// The number of chunks to add to the start and end of speech.
+    #[arg(short, long, default_value_t = 3)]
+    padding_chunks: usize,
+
+    /// Speech segments shorter than this many seconds are discarded.
+    #[arg(short, long, default_value_t = 0.0)]
+    min_duration: f64,
+
+    /// The chunk size for the detector. Defaults to the smallest power of two,
+    /// no less than 256, that the model accepts at the sample rate: 256 samples
+    /// at 8 kHz and 512 at 16 kHz.
+    #[arg(long)]
+    chunk_size: Option<usize>,
+
+    /// How to print the speech timestamps.
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Reads the input as raw PCM of the given encoding rather than WAV.
+    #[arg(long, value_enum, requires = "sample_rate")]
+    raw: Option<RawEncoding>,
+
+    /// The sample rate of raw PCM input.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    sample_rate: Option<u32>,
+
+    /// The number of interleaved channels in raw PCM input. The channels are
+    /// mixed down to mono, as for WAV input.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    channels: u16,
+
+    /// Writes all speech audio to a single mono WAV file.
+    #[arg(long)]
+    speech_output: Option<PathBuf>,
+
+    /// Writes each speech segment to its own mono WAV file in this directory.
+    #[arg(long)]
+    segment_dir: Option<PathBuf>,
+
+    /// The JSONL manifest to append to when the input is a directory. Files
+    /// already in the manifest are skipped. Per-file outputs and filters are
+    /// not available for directories.
+    #[arg(
+        long,
+        conflicts_with_all = ["min_duration", "format", "speech_output", "segment_dir"]
+    )]
+    manifest: Option<PathBuf>,
+
+    /// The number of files to process in parallel when the input is a directory.
+    #[arg(short, long)]
+    jobs: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+    Srt,
+    Webvtt,
+    Audacity,
+    Rttm,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum RawEncoding {
+    S16le,
+    S16be,
+    F32le,
+    F32be,
+}
+
+fn parse_threshold(value: &str) -> Result<f32, String> {
+    let threshold: f32 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("the threshold must be between 0.0 and 1.0".into());
+    }
+    Ok(threshold)
+}
+
+fn main() {
+    let args = Args::parse();
+    if let Err(error) = run(&args) {
+        eprintln!("error: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    if Path::new(&args.input).is_dir() {
+        return run_batch(args);
+    }
+
+    let input: Box<dyn Read> = match args.input.as_str() {
+        "-" => Box::new(io::stdin().lock()),
+        path => Box::new(File::open(path)?),
+    };
+    let input = BufReader::new(input);
+
+    let options = wav_options(args);
+    let segments = match args.raw {
+        None => {
+            let labels = WavLabels::new(WavReader::new(input)?, &options)?;
+            let spec = WavSpec {
+                channels: 1,
+                ..labels.spec()
+            };
+            let mut output = SegmentOutput::new(args, spec);
+            for audio in labels {
+                output.push(audio?)?;
+            }
+            output.finish()?
+        }
+        Some(encoding) => {
+            let (endianness, spec) = match encoding {
+                RawEncoding::S16le => (Endianness::Little, (16, SampleFormat::Int)),
+                RawEncoding::S16be => (Endianness::Big, (16, SampleFormat::Int)),
+                RawEncoding::F32le => (Endianness::Little, (32, SampleFormat::Float)),
+                RawEncoding::F32be => (Endianness::Big, (32, SampleFormat::Float)),
+            };
+            let format = PcmFormat::builder()
+                .sample_rate(args.sample_rate.unwrap_or_default())
+                .endianness(endianness)
+                .channels(args.channels)
+                .build();
+            let mut output = SegmentOutput::new(
+                args,
+                WavSpec {
+                    channels: 1,
+                    sample_rate: format.sample_rate,
+                    bits_per_sample: spec.0,
+                    sample_format: spec.1,
+                },
+            );
+
+            match spec.1 {
+                SampleFormat::Int => label_raw::<i16>(input, format, &options, &mut output)?,
+                SampleFormat::Float => label_raw::<f32>(input, format, &options, &mut output)?,
+            }
+            output.finish()?
+        }
+    };
+
+    print_segments(&segments, args.format, &input_name(args))?;
+
+    Ok(())
+}
+
+fn wav_options(args: &Args) -> WavOptions {
+    WavOptions {
+        threshold: args.threshold,
+        padding_chunks: args.padding_chunks,
+        chunk_size: args.chunk_size,
+        segment_dir: None,
+    }
+}
+
+/// Processes every WAV file in the input directory, appending to the manifest.
+fn run_batch(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let manifest = args
+        .manifest
+        .clone()
+        .ok_or("--manifest is required when the input is a directory")?;
+    let options = BatchOptions {
+        manifest,
+        wav: wav_options(args),
+        workers: args.jobs,
+        extensions: vec!["wav".into()],
+    };
+
+    let summary = process_directory(&args.input, &options)?;
+    eprintln!(
+        "processed {} files, {} failed, {} skipped",
+        summary.processed, summary.failed, summary.skipped
+    );
+
+    Ok(())
+}
+
+/// Labels raw PCM input, pushing the labeled audio to the output.
+fn label_raw<T>(
+    input: impl Read,
+    format: PcmFormat,
+    options: &WavOptions,
+    output: &mut SegmentOutput,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    T: voice_activity_detector::PcmSample,
+{
+    let mut vad = VoiceActivityDetector::builder()
+        .sample_rate(format.sample_rate)
+        .chunk_size(options.chunk_size_for(format.sample_rate))
+        .build()?;
+
+    // Read every interleaved sample, then mix each frame down to mono.
+    let channels = usize::from(format.channels);
+    let mut reader = PcmReader::<_, T>::new(
+        input,
+        PcmFormat {
+            channels: 1,
+            ..format
+        },
+    )?;
+    let mut samples = reader.samples().map(Sample::to_f32);
+    let mono = std::iter::from_fn(|| {
+        let mut sum = 0.0;
+        for _ in 0..channels {
+            sum += samples.next()?;
+        }
+        Some(sum / channels as f32)
+    });
+    for audio in mono.label(&mut vad, options.threshold, options.padding_chunks) {
+        output.push(audio)?;
+    }
+    reader.take_error()?;
+
+    Ok(())
+}
+
+/// Collects speech segments, writing their audio to any requested WAV files.
+struct SegmentOutput {
+    spec: WavSpec,
+    segmenter: Segmenter,
+    speech: Vec<f32>,
+    segments: Vec<SpeechSegment>,
+    speech_output: Option<PathBuf>,
+    speech_writer: Option<WavWriter<BufWriter<File>>>,
+    segment_dir: Option<PathBuf>,
+    name: String,
+}
+
+impl SegmentOutput {
+    fn new(args: &Args, spec: WavSpec) -> Self {
+        Self {
+            spec,
+            segmenter: Segmenter::new(spec.sample_rate).with_min_duration(args.min_duration),
+            speech: Vec::new(),
+            segments: Vec::new(),
+            speech_output: args.speech_output.clone(),
+            speech_writer: None,
+            segment_dir: args.segment_dir.clone(),
+            name: input_name(args),
+        }
+    }
+
+    fn push(&mut self, audio: LabeledAudio<f32>) -> Result<(), Error> {
+        let segment = self.segmenter.push(&audio);
+        if audio.is_speech() {
+            self.speech.extend(audio.iter());
+            return Ok(());
+        }
+        self.end_speech(segment)
+    }
+
+    /// Writes the current speech if the segmenter kept its segment.
+    fn end_speech(&mut self, segment: Option<SpeechSegment>) -> Result<(), Error> {
+        let speech = std::mem::take(&mut self.speech);
+        let Some(segment) = segment else {
+            return Ok(());
+        };
+
+        if let Some(path) = &self.speech_output {
+            if self.speech_writer.is_none() {
+                self.speech_writer = Some(WavWriter::create(path, self.spec)?);
+            }
+        }
+        if let Some(writer) = self.speech_writer.as_mut() {
+            write_wav_samples(writer, &speech)?;
+        }
+        if let Some(dir) = &self.segment_dir {
+            let path = dir.join(format!("{}.{}.wav", self.name, self.segments.len()));
+            let mut writer = WavWriter::create(path, self.spec)?;
+            write_wav_samples(&mut writer, &speech)?;
+            writer.finalize()?;
+        }
+
+        self.segments.push(segment);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Vec<SpeechSegment>, Error> {
+        let segment = self.segmenter.finish();
+        self.end_speech(segment)?;
+        if let Some(writer) = self.speech_writer {
+            writer.finalize()?;
+        } else if let Some(path) = &self.speech_output {
+            // Write an empty file when no speech was found, so the output always exists.
+            WavWriter::create(path, self.spec)?.finalize()?;
+        }
+        Ok(self.segments)
+    }
+}
+
+/// The name of the input file without its extension, used to name outputs.
+fn input_name(args: &Args) -> String {
+    match args.input.as_str() {
+        "-" => "segment".into(),
+        path => Path::new(path)
+            .file_stem()
+            .map_or_else(|| "segment".into(), |stem| stem.to_string_lossy().into()),
+    }
+}
+
+fn print_segments(segments: &[SpeechSegment], format: OutputFormat, name: &str) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    match format {
+        OutputFormat::Text => {
+            for segment in segments {
+                writeln!(stdout, "{:.3}\t{:.3}", segment.start, segment.end)?;
+            }
+        }
+        OutputFormat::Csv => {
+            writeln!(stdout, "start,end")?;
+            for segment in segments {
+                writeln!(stdout, "{:.3},{:.3}", segment.start, segment.end)?;
+            }
+        }
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, segments)?;
+            writeln!(stdout)?;
+        }
+        OutputFormat::Srt => write_srt(stdout, segments)?,
+        OutputFormat::Webvtt => write_webvtt(stdout, segments)?,
+        OutputFormat::Audacity => write_audacity(stdout, segments)?,
+        OutputFormat::Rttm => write_rttm(stdout, segments, name)?,
+    }
+    Ok(())
+}
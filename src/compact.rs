@@ -0,0 +1,190 @@
+use crate::{LabeledAudio, Sample};
+
+/// Options for joining speech with [compact].
+#[derive(Clone, Copy, Debug, typed_builder::TypedBuilder)]
+pub struct CompactOptions {
+    /// The number of samples faded between segments. Without a gap, the end of
+    /// each segment is crossfaded with the start of the next.
+    #[builder(default = 80)]
+    pub fade: usize,
+    /// The number of samples of silence inserted between segments.
+    #[builder(default = 0)]
+    pub gap: usize,
+}
+
+impl Default for CompactOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The speech of a recording joined together, with a map back to the original.
+#[derive(Clone, Debug)]
+pub struct Compacted<T> {
+    /// The joined speech audio.
+    pub samples: Vec<T>,
+    /// Translates positions between the joined and original audio.
+    pub map: OffsetMap,
+}
+
+/// A run of speech copied from the original audio into the compacted audio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetSegment {
+    /// The sample position of the run in the compacted audio.
+    pub compacted: usize,
+    /// The sample position of the run in the original audio.
+    pub original: usize,
+    /// The number of samples in the run.
+    pub len: usize,
+}
+
+/// Translates sample positions between compacted and original audio.
+///
+/// Within a crossfade, positions belong to the later segment.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetMap {
+    segments: Vec<OffsetSegment>,
+}
+
+impl OffsetMap {
+    /// The speech segments, in order.
+    pub fn segments(&self) -> &[OffsetSegment] {
+        &self.segments
+    }
+
+    /// Returns the position in the original audio of a position in the
+    /// compacted audio. Positions in an inserted gap map to the end of the
+    /// segment before it.
+    pub fn to_original(&self, compacted: usize) -> usize {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.compacted <= compacted);
+        // The first segment starts at zero, so this only fails without speech.
+        let Some(segment) = index.checked_sub(1).map(|i| self.segments[i]) else {
+            return compacted;
+        };
+        segment.original + (compacted - segment.compacted).min(segment.len)
+    }
+
+    /// Returns the position in the compacted audio of a position in the
+    /// original audio. Positions in removed audio map to where it was cut.
+    pub fn to_compacted(&self, original: usize) -> usize {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.original <= original);
+        let Some(segment) = index.checked_sub(1).map(|i| self.segments[i]) else {
+            return 0;
+        };
+        if original < segment.original + segment.len {
+            return segment.compacted + (original - segment.original);
+        }
+        match self.segments.get(index) {
+            Some(next) => next.compacted,
+            None => segment.compacted + segment.len,
+        }
+    }
+}
+
+/// Joins the speech of labeled audio, removing non-speech.
+///
+/// Segments are crossfaded together so the joins do not click, or faded out
+/// and in around silence when [CompactOptions::gap] is set. The returned
+/// [OffsetMap] translates positions, such as word times from a transcript of
+/// the compacted audio, back to the original recording.
+///
+/// ```rust
+/// use voice_activity_detector::{compact, CompactOptions, LabeledAudio};
+///
+/// let labels = vec![
+///     LabeledAudio::Speech(vec![100i16; 400]),
+///     LabeledAudio::NonSpeech(vec![0; 1000]),
+///     LabeledAudio::Speech(vec![100; 400]),
+/// ];
+/// let compacted = compact(labels, &CompactOptions::builder().fade(40).build());
+///
+/// assert_eq!(760, compacted.samples.len());
+/// assert_eq!(1500, compacted.map.to_original(460));
+/// assert_eq!(460, compacted.map.to_compacted(1500));
+/// ```
+pub fn compact<T, I>(labels: I, options: &CompactOptions) -> Compacted<T>
+where
+    T: Sample,
+    I: IntoIterator<Item = LabeledAudio<T>>,
+{
+    // Group consecutive speech chunks into segments with their original position.
+    let mut segments: Vec<(usize, Vec<T>)> = Vec::new();
+    let mut position = 0;
+    let mut previous_speech = false;
+    for audio in labels {
+        let len = audio.len();
+        let speech = audio.is_speech();
+        if speech {
+            match segments.last_mut() {
+                Some((_, samples)) if previous_speech => samples.extend(audio),
+                _ => segments.push((position, audio.into_iter().collect())),
+            }
+        }
+        previous_speech = speech;
+        position += len;
+    }
+
+    let mut samples: Vec<T> = Vec::new();
+    let mut map = OffsetMap::default();
+    for (original, mut speech) in segments {
+        let len = speech.len();
+        let compacted = match map.segments.last() {
+            None => 0,
+            Some(previous) => {
+                let fade = options.fade.min(previous.len).min(len);
+                if options.gap > 0 {
+                    fade_out(&mut samples, fade);
+                    samples.resize(samples.len() + options.gap, T::default());
+                    fade_in(&mut speech, fade);
+                    samples.len()
+                } else {
+                    let start = samples.len() - fade;
+                    crossfade(&mut samples[start..], &speech[..fade]);
+                    speech.drain(..fade);
+                    start
+                }
+            }
+        };
+        samples.extend(speech);
+        map.segments.push(OffsetSegment {
+            compacted,
+            original,
+            len,
+        });
+    }
+
+    Compacted { samples, map }
+}
+
+/// The weight of the incoming audio at step `i` of a fade of `len` samples.
+fn weight(i: usize, len: usize) -> f32 {
+    (i + 1) as f32 / (len + 1) as f32
+}
+
+/// Mixes the start of the next segment into the end of the previous one.
+fn crossfade<T: Sample>(tail: &mut [T], head: &[T]) {
+    let len = head.len();
+    for (i, (out, &next)) in tail.iter_mut().zip(head).enumerate() {
+        let w = weight(i, len);
+        *out = T::from_f32(out.to_f32() * (1.0 - w) + next.to_f32() * w);
+    }
+}
+
+fn fade_out<T: Sample>(samples: &mut [T], len: usize) {
+    let start = samples.len() - len;
+    for (i, sample) in samples[start..].iter_mut().enumerate() {
+        *sample = T::from_f32(sample.to_f32() * (1.0 - weight(i, len)));
+    }
+}
+
+fn fade_in<T: Sample>(samples: &mut [T], len: usize) {
+    for (i, sample) in samples[..len].iter_mut().enumerate() {
+        *sample = T::from_f32(sample.to_f32() * weight(i, len));
+    }
+}
@@ -0,0 +1,370 @@
+use std::sync::Arc;
+
+use ort::session::Session;
+
+use crate::eval::merge;
+use crate::vad::DetectorConfig;
+use crate::{Error, LatencyStats, Sample, Segmenter, SpeechSegment, VadProcessor};
+
+/// A party in a two-party conversation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Speaker {
+    /// The agent, on the left channel.
+    Agent,
+    /// The customer, on the right channel.
+    Customer,
+}
+
+/// A span of speech by one party, in seconds from the start of the audio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Turn {
+    /// Who was speaking.
+    pub speaker: Speaker,
+    /// The start of the speech, in seconds.
+    pub start: f64,
+    /// The end of the speech, in seconds.
+    pub end: f64,
+}
+
+impl Turn {
+    /// The length of the turn in seconds.
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// A span where neither party was speaking, in seconds from the start of the
+/// audio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SilenceGap {
+    /// The start of the silence, in seconds.
+    pub start: f64,
+    /// The end of the silence, in seconds.
+    pub end: f64,
+}
+
+impl SilenceGap {
+    /// The length of the silence in seconds.
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// The analysis of a two-party conversation.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConversationReport {
+    /// The length of the recording, in seconds.
+    pub duration: f64,
+    /// Who spoke when, ordered by start time.
+    pub turns: Vec<Turn>,
+    /// Spans where both parties were speaking at once.
+    pub overlaps: Vec<SpeechSegment>,
+    /// Seconds the agent spent speaking.
+    pub agent_talk_time: f64,
+    /// Seconds the customer spent speaking.
+    pub customer_talk_time: f64,
+    /// Seconds both parties spent speaking at once.
+    pub overlap_time: f64,
+    /// The agent's share of the total talk time, or `0.0` if nobody spoke.
+    pub agent_talk_ratio: f64,
+    /// How long the agent took to start speaking after the customer stopped.
+    /// Negative values mean the agent started before the customer finished.
+    pub agent_response_latency: LatencyStats,
+    /// How long the customer took to start speaking after the agent stopped.
+    /// Negative values mean the customer started before the agent finished.
+    pub customer_response_latency: LatencyStats,
+    /// The longest span one party spoke without the other taking a turn.
+    pub longest_monologue: Option<Turn>,
+    /// Spans between the first and last speech where neither party spoke.
+    pub silence_gaps: Vec<SilenceGap>,
+    /// The longest of the silence gaps.
+    pub longest_silence: Option<SilenceGap>,
+}
+
+impl ConversationReport {
+    /// Analyzes the speech segments of each party in a recording of the given
+    /// duration in seconds.
+    pub fn new(agent: &[SpeechSegment], customer: &[SpeechSegment], duration: f64) -> Self {
+        let agent = merge(agent);
+        let customer = merge(customer);
+
+        let mut turns: Vec<Turn> = agent
+            .iter()
+            .map(|segment| turn(Speaker::Agent, segment))
+            .chain(
+                customer
+                    .iter()
+                    .map(|segment| turn(Speaker::Customer, segment)),
+            )
+            .collect();
+        turns.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        let overlaps = intersect(&agent, &customer);
+        let agent_talk_time: f64 = agent.iter().map(SpeechSegment::duration).sum();
+        let customer_talk_time: f64 = customer.iter().map(SpeechSegment::duration).sum();
+        let total_talk_time = agent_talk_time + customer_talk_time;
+
+        let mut agent_latencies = Vec::new();
+        let mut customer_latencies = Vec::new();
+        for pair in turns.windows(2) {
+            let (previous, next) = (pair[0], pair[1]);
+            let latency = next.start - previous.end;
+            match (previous.speaker, next.speaker) {
+                (Speaker::Customer, Speaker::Agent) => agent_latencies.push(latency),
+                (Speaker::Agent, Speaker::Customer) => customer_latencies.push(latency),
+                _ => {}
+            }
+        }
+
+        let mut monologues: Vec<Turn> = Vec::new();
+        for &turn in &turns {
+            match monologues.last_mut() {
+                Some(last) if last.speaker == turn.speaker => last.end = last.end.max(turn.end),
+                _ => monologues.push(turn),
+            }
+        }
+        let longest_monologue = monologues
+            .into_iter()
+            .max_by(|a, b| a.duration().total_cmp(&b.duration()));
+
+        let speech = merge(&[agent.as_slice(), customer.as_slice()].concat());
+        let silence_gaps: Vec<SilenceGap> = speech
+            .windows(2)
+            .map(|pair| SilenceGap {
+                start: pair[0].end,
+                end: pair[1].start,
+            })
+            .collect();
+        let longest_silence = silence_gaps
+            .iter()
+            .copied()
+            .max_by(|a, b| a.duration().total_cmp(&b.duration()));
+
+        Self {
+            duration,
+            turns,
+            overlap_time: overlaps.iter().map(SpeechSegment::duration).sum(),
+            overlaps,
+            agent_talk_time,
+            customer_talk_time,
+            agent_talk_ratio: if total_talk_time > 0.0 {
+                agent_talk_time / total_talk_time
+            } else {
+                0.0
+            },
+            agent_response_latency: LatencyStats::new(agent_latencies),
+            customer_response_latency: LatencyStats::new(customer_latencies),
+            longest_monologue,
+            silence_gaps,
+            longest_silence,
+        }
+    }
+}
+
+fn turn(speaker: Speaker, segment: &SpeechSegment) -> Turn {
+    Turn {
+        speaker,
+        start: segment.start,
+        end: segment.end,
+    }
+}
+
+/// Returns the spans covered by both sorted, non-overlapping lists of segments.
+fn intersect(a: &[SpeechSegment], b: &[SpeechSegment]) -> Vec<SpeechSegment> {
+    let mut overlaps = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].start.max(b[j].start);
+        let end = a[i].end.min(b[j].end);
+        if start < end {
+            overlaps.push(SpeechSegment::new(start, end));
+        }
+        if a[i].end < b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    overlaps
+}
+
+/// Options for a [ConversationAnalyzer].
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct ConversationOptions {
+    /// The sample rate of the recording.
+    #[builder(setter(into))]
+    pub sample_rate: i64,
+    /// The chunk size for the detectors.
+    #[builder(setter(into))]
+    pub chunk_size: usize,
+    /// Probabilities greater than or equal to this value are considered speech.
+    #[builder(default = 0.5)]
+    pub threshold: f32,
+    /// The number of chunks to add to the start and end of speech.
+    #[builder(default = 3)]
+    pub padding_chunks: usize,
+    /// The session shared by the detectors. Defaults to the bundled model.
+    #[builder(default, setter(strip_option))]
+    pub session: Option<Arc<Session>>,
+}
+
+/// Analyzes a stereo recording of a two-party conversation, with the agent on
+/// the left channel and the customer on the right.
+///
+/// Each channel is labeled by its own detector. Both channels advance by the
+/// same number of samples on every push, so their labels stay aligned in time.
+///
+/// ```rust,no_run
+/// fn main() -> Result<(), voice_activity_detector::Error> {
+///     use voice_activity_detector::{ConversationAnalyzer, ConversationOptions};
+///
+///     let options = ConversationOptions::builder()
+///         .sample_rate(8000)
+///         .chunk_size(256usize)
+///         .build();
+///     let mut analyzer = ConversationAnalyzer::<i16>::new(options)?;
+///
+///     // Interleaved stereo samples, left then right.
+///     analyzer.push(&[0i16; 16000]);
+///     let report = analyzer.finish();
+///     println!("agent talk ratio: {:.2}", report.agent_talk_ratio);
+///     Ok(())
+/// }
+/// ```
+pub struct ConversationAnalyzer<T> {
+    sample_rate: u32,
+    agent: Channel<T>,
+    customer: Channel<T>,
+    left: Vec<T>,
+    right: Vec<T>,
+    pending: Option<T>,
+    frames: usize,
+}
+
+struct Channel<T> {
+    processor: VadProcessor<T>,
+    segmenter: Segmenter,
+    segments: Vec<SpeechSegment>,
+}
+
+impl<T: Sample> Channel<T> {
+    fn push(&mut self, samples: &[T]) {
+        for audio in self.processor.push(samples) {
+            self.segments.extend(self.segmenter.push(&audio));
+        }
+    }
+
+    fn finish(&mut self) {
+        for audio in self.processor.finish() {
+            self.segments.extend(self.segmenter.push(&audio));
+        }
+        self.segments.extend(self.segmenter.finish());
+    }
+}
+
+impl<T: Sample> ConversationAnalyzer<T> {
+    /// Creates an analyzer with a detector for each channel.
+    pub fn new(options: ConversationOptions) -> Result<Self, Error> {
+        let config = DetectorConfig::new(
+            options.sample_rate,
+            options.chunk_size,
+            options.session.clone(),
+        )?;
+        let sample_rate =
+            u32::try_from(options.sample_rate).map_err(|_| Error::VadConfigError {
+                sample_rate: options.sample_rate,
+                chunk_size: options.chunk_size,
+            })?;
+        let channel = || Channel {
+            processor: VadProcessor::new(
+                config.detector(),
+                options.threshold,
+                options.padding_chunks,
+            ),
+            segmenter: Segmenter::new(sample_rate),
+            segments: Vec::new(),
+        };
+
+        Ok(Self {
+            sample_rate,
+            agent: channel(),
+            customer: channel(),
+            left: Vec::new(),
+            right: Vec::new(),
+            pending: None,
+            frames: 0,
+        })
+    }
+
+    /// Pushes interleaved stereo samples, left then right.
+    ///
+    /// A trailing left sample without its right sample is kept until the next
+    /// push.
+    pub fn push(&mut self, samples: &[T]) {
+        self.left.clear();
+        self.right.clear();
+
+        let mut samples = samples.iter().copied();
+        if let Some(left) = self.pending.take() {
+            match samples.next() {
+                Some(right) => {
+                    self.left.push(left);
+                    self.right.push(right);
+                }
+                None => {
+                    self.pending = Some(left);
+                    return;
+                }
+            }
+        }
+        while let Some(left) = samples.next() {
+            match samples.next() {
+                Some(right) => {
+                    self.left.push(left);
+                    self.right.push(right);
+                }
+                None => self.pending = Some(left),
+            }
+        }
+
+        self.frames += self.left.len();
+        self.agent.push(&self.left);
+        self.customer.push(&self.right);
+    }
+
+    /// Pushes the samples of each channel separately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channels have different lengths.
+    pub fn push_channels(&mut self, left: &[T], right: &[T]) {
+        assert_eq!(
+            left.len(),
+            right.len(),
+            "channels must have the same length"
+        );
+        self.frames += left.len();
+        self.agent.push(left);
+        self.customer.push(right);
+    }
+
+    /// Returns the speech segments of a party found so far.
+    pub fn segments(&self, speaker: Speaker) -> &[SpeechSegment] {
+        match speaker {
+            Speaker::Agent => &self.agent.segments,
+            Speaker::Customer => &self.customer.segments,
+        }
+    }
+
+    /// Ends the recording and returns the report.
+    pub fn finish(mut self) -> ConversationReport {
+        self.agent.finish();
+        self.customer.finish();
+        let duration = self.frames as f64 / f64::from(self.sample_rate);
+        ConversationReport::new(&self.agent.segments, &self.customer.segments, duration)
+    }
+}
@@ -0,0 +1,221 @@
+use crate::LabeledAudio;
+
+/// The outcome of a turn, with times in seconds from the start of the audio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Endpoint {
+    /// No speech started within the no-input timeout.
+    NoInput {
+        /// When the timeout expired.
+        at: f64,
+    },
+    /// Speech was followed by enough trailing silence.
+    EndOfSpeech {
+        /// When speech started.
+        start: f64,
+        /// When speech ended, before the trailing silence.
+        end: f64,
+    },
+    /// Speech went on for longer than the maximum utterance length.
+    MaxLength {
+        /// When speech started.
+        start: f64,
+        /// When the utterance was cut off.
+        end: f64,
+    },
+}
+
+/// Options for an [Endpointer], in seconds.
+#[derive(Clone, Copy, Debug, typed_builder::TypedBuilder)]
+pub struct EndpointOptions {
+    /// How long to wait for speech to start before giving up with
+    /// [Endpoint::NoInput].
+    #[builder(default = 5.0)]
+    pub no_input_timeout: f64,
+    /// How much silence after speech ends the utterance with
+    /// [Endpoint::EndOfSpeech].
+    #[builder(default = 0.8)]
+    pub end_silence: f64,
+    /// The longest utterance before it is cut off with [Endpoint::MaxLength].
+    #[builder(default = 15.0)]
+    pub max_length: f64,
+}
+
+impl Default for EndpointOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Detects the end of each turn of a conversation from [LabeledAudio].
+///
+/// A turn starts at the beginning of the audio and after every endpoint. Each
+/// turn ends with exactly one [Endpoint]. Chunks padded by the labeler count as
+/// speech, so the trailing silence is measured after the padding.
+///
+/// ```rust
+/// use voice_activity_detector::{Endpoint, EndpointOptions, Endpointer, LabeledAudio};
+///
+/// let options = EndpointOptions::builder().end_silence(0.5).build();
+/// let mut endpointer = Endpointer::new(8000, options);
+/// let labels = [
+///     LabeledAudio::NonSpeech(vec![0i16; 4000]),
+///     LabeledAudio::Speech(vec![0i16; 8000]),
+///     LabeledAudio::NonSpeech(vec![0i16; 4000]),
+/// ];
+///
+/// let endpoints: Vec<Endpoint> = labels.iter().filter_map(|audio| endpointer.push(audio)).collect();
+/// assert_eq!(endpoints, vec![Endpoint::EndOfSpeech { start: 0.5, end: 1.5 }]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Endpointer {
+    sample_rate: u32,
+    no_input_timeout: usize,
+    end_silence: usize,
+    max_length: usize,
+    position: usize,
+    turn_start: usize,
+    speech: Option<(usize, usize)>,
+}
+
+impl Endpointer {
+    /// Creates a new endpointer for audio of the given sample rate.
+    pub fn new(sample_rate: u32, options: EndpointOptions) -> Self {
+        let samples = |seconds: f64| (seconds * f64::from(sample_rate)).round() as usize;
+        Self {
+            sample_rate,
+            no_input_timeout: samples(options.no_input_timeout),
+            end_silence: samples(options.end_silence),
+            max_length: samples(options.max_length),
+            position: 0,
+            turn_start: 0,
+            speech: None,
+        }
+    }
+
+    /// The number of samples pushed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Pushes the next labeled chunk, returning an endpoint if the turn ended.
+    pub fn push<T>(&mut self, audio: &LabeledAudio<T>) -> Option<Endpoint> {
+        let start = self.position;
+        self.position += audio.len();
+        let end = self.position;
+
+        match (self.speech, audio.is_speech()) {
+            (None, true) => {
+                self.speech = Some((start, end));
+                self.check_max_length(start, end)
+            }
+            (None, false) => {
+                if end - self.turn_start < self.no_input_timeout {
+                    return None;
+                }
+                self.turn_start = end;
+                Some(Endpoint::NoInput {
+                    at: self.seconds(end),
+                })
+            }
+            (Some((speech_start, _)), true) => {
+                self.speech = Some((speech_start, end));
+                self.check_max_length(speech_start, end)
+            }
+            (Some((speech_start, speech_end)), false) => {
+                if end - speech_end < self.end_silence {
+                    return None;
+                }
+                self.next_turn();
+                Some(Endpoint::EndOfSpeech {
+                    start: self.seconds(speech_start),
+                    end: self.seconds(speech_end),
+                })
+            }
+        }
+    }
+
+    /// Ends the audio, returning [Endpoint::EndOfSpeech] if it ended during speech.
+    pub fn finish(&mut self) -> Option<Endpoint> {
+        let (start, end) = self.speech?;
+        self.next_turn();
+        Some(Endpoint::EndOfSpeech {
+            start: self.seconds(start),
+            end: self.seconds(end),
+        })
+    }
+
+    /// Starts a new turn at the current position.
+    pub fn reset(&mut self) {
+        self.next_turn();
+    }
+
+    fn check_max_length(&mut self, start: usize, end: usize) -> Option<Endpoint> {
+        if end - start < self.max_length {
+            return None;
+        }
+        self.next_turn();
+        Some(Endpoint::MaxLength {
+            start: self.seconds(start),
+            end: self.seconds(end),
+        })
+    }
+
+    fn next_turn(&mut self) {
+        self.turn_start = self.position;
+        self.speech = None;
+    }
+
+    fn seconds(&self, samples: usize) -> f64 {
+        samples as f64 / f64::from(self.sample_rate)
+    }
+}
+
+/// Yields the [Endpoint] of each turn in an iterator of labeled audio.
+pub struct EndpointIterator<I> {
+    iter: I,
+    endpointer: Endpointer,
+    done: bool,
+}
+
+impl<I> EndpointIterator<I> {
+    pub(crate) fn new(iter: I, endpointer: Endpointer) -> Self {
+        Self {
+            iter,
+            endpointer,
+            done: false,
+        }
+    }
+
+    /// Returns the endpointer, to start a new turn or check the position.
+    pub fn endpointer_mut(&mut self) -> &mut Endpointer {
+        &mut self.endpointer
+    }
+
+    /// Consumes the iterator, returning the labeled audio iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<T, I> Iterator for EndpointIterator<I>
+where
+    I: Iterator<Item = LabeledAudio<T>>,
+{
+    type Item = Endpoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        for audio in self.iter.by_ref() {
+            if let Some(endpoint) = self.endpointer.push(&audio) {
+                return Some(endpoint);
+            }
+        }
+
+        self.done = true;
+        self.endpointer.finish()
+    }
+}
@@ -1,5 +1,6 @@
 /// An enum of all errors returned by the voice activity detector functions.
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// The VAD configuration must use a smaller chunk size or sample rate.
     #[error("the chunk size {chunk_size} is too small for the provided sample rate {sample_rate}")]
@@ -9,4 +10,49 @@ pub enum Error {
         /// The chunk size for the VAD.
         chunk_size: usize,
     },
+    /// The evaluation frame length must be a positive number of seconds.
+    #[error("the frame length {frame} must be a positive number of seconds")]
+    EvalConfigError {
+        /// The frame length for the evaluation.
+        frame: f64,
+    },
+    /// The other half of a channel was dropped, such as when the receiver of a
+    /// sink or a batched inference thread stopped.
+    #[error("the receiving half of the channel was dropped")]
+    Disconnected,
+    /// A line of a segment file could not be parsed.
+    #[error("line {line}: {message}")]
+    ParseError {
+        /// The line number, starting at 1.
+        line: usize,
+        /// A description of the problem.
+        message: String,
+    },
+    /// A raw PCM format has no channels, or selects a channel it does not have.
+    #[error("channel {channel} does not exist in a PCM format with {channels} channels")]
+    PcmFormatError {
+        /// The number of interleaved channels.
+        channels: u16,
+        /// The selected channel.
+        channel: u16,
+    },
+    /// A probability trace is missing its header or has an invalid one.
+    #[error("invalid probability trace: {message}")]
+    TraceFormatError {
+        /// A description of the problem.
+        message: String,
+    },
+    /// A snapshot does not match the detector it is restored into.
+    #[error("the snapshot does not match the detector: {message}")]
+    SnapshotError {
+        /// A description of the mismatch.
+        message: String,
+    },
+    /// An I/O error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// An error reading or writing a WAV file.
+    #[cfg(feature = "wav")]
+    #[error(transparent)]
+    Wav(#[from] hound::Error),
 }
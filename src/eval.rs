@@ -0,0 +1,251 @@
+use crate::{Error, SpeechSegment};
+
+/// Options for comparing hypothesis segments to reference segments.
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct EvalOptions {
+    /// The length of each scored frame, in seconds. Must be positive.
+    #[builder(default = 0.01)]
+    pub frame: f64,
+    /// Frames within this many seconds of a reference boundary are not scored.
+    #[builder(default = 0.0)]
+    pub collar: f64,
+    /// The total duration of the audio, in seconds. Defaults to the end of the
+    /// last segment.
+    #[builder(default, setter(strip_option))]
+    pub duration: Option<f64>,
+}
+
+impl EvalOptions {
+    /// Checks that the frame length is positive.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        if self.frame > 0.0 {
+            return Ok(());
+        }
+        Err(Error::EvalConfigError { frame: self.frame })
+    }
+}
+
+impl Default for EvalOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Summary statistics for a set of latencies, in seconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatencyStats {
+    /// The number of latencies measured.
+    pub count: usize,
+    /// The mean latency.
+    pub mean: f64,
+    /// The median latency.
+    pub median: f64,
+    /// The smallest latency.
+    pub min: f64,
+    /// The largest latency.
+    pub max: f64,
+}
+
+impl LatencyStats {
+    pub(crate) fn new(mut latencies: Vec<f64>) -> Self {
+        if latencies.is_empty() {
+            return Self::default();
+        }
+
+        latencies.sort_by(f64::total_cmp);
+        let count = latencies.len();
+        let median = if count % 2 == 0 {
+            (latencies[count / 2 - 1] + latencies[count / 2]) / 2.0
+        } else {
+            latencies[count / 2]
+        };
+
+        Self {
+            count,
+            mean: latencies.iter().sum::<f64>() / count as f64,
+            median,
+            min: latencies[0],
+            max: latencies[count - 1],
+        }
+    }
+}
+
+/// The result of comparing hypothesis segments to reference segments.
+///
+/// Rates with an empty denominator are reported as `0.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvalReport {
+    /// Seconds of reference speech that were scored.
+    pub speech: f64,
+    /// Seconds of reference non-speech that were scored.
+    pub non_speech: f64,
+    /// Seconds of non-speech labeled as speech.
+    pub false_alarm: f64,
+    /// Seconds of speech labeled as non-speech.
+    pub missed: f64,
+    /// The fraction of hypothesis speech that is reference speech.
+    pub precision: f64,
+    /// The fraction of reference speech that is hypothesis speech.
+    pub recall: f64,
+    /// The harmonic mean of precision and recall.
+    pub f1: f64,
+    /// The fraction of reference non-speech labeled as speech.
+    pub false_alarm_rate: f64,
+    /// The fraction of reference speech labeled as non-speech.
+    pub miss_rate: f64,
+    /// False alarms and missed speech, as a fraction of reference speech.
+    pub detection_error_rate: f64,
+    /// How long after each reference segment starts the overlapping hypothesis
+    /// speech starts. Negative values mean speech was detected early.
+    pub onset_latency: LatencyStats,
+    /// How long after each reference segment ends the overlapping hypothesis
+    /// speech ends. Negative values mean speech was cut off early.
+    pub offset_latency: LatencyStats,
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        0.0
+    }
+}
+
+/// Sorts segments and merges any that overlap.
+pub(crate) fn merge(segments: &[SpeechSegment]) -> Vec<SpeechSegment> {
+    let mut sorted: Vec<SpeechSegment> = segments
+        .iter()
+        .copied()
+        .filter(|segment| segment.end > segment.start)
+        .collect();
+    sorted.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+    let mut merged: Vec<SpeechSegment> = Vec::with_capacity(sorted.len());
+    for segment in sorted {
+        match merged.last_mut() {
+            Some(last) if segment.start <= last.end => last.end = last.end.max(segment.end),
+            _ => merged.push(segment),
+        }
+    }
+    merged
+}
+
+/// Tracks whether increasing times fall within a sorted list of segments.
+struct Cursor<'a> {
+    segments: &'a [SpeechSegment],
+    index: usize,
+}
+
+impl Cursor<'_> {
+    fn contains(&mut self, time: f64) -> bool {
+        while self.index < self.segments.len() && self.segments[self.index].end <= time {
+            self.index += 1;
+        }
+        self.segments
+            .get(self.index)
+            .is_some_and(|segment| segment.start <= time)
+    }
+}
+
+/// Compares hypothesis segments, such as those labeled by the detector, to
+/// reference segments from ground truth annotations.
+///
+/// The audio is split into frames of [EvalOptions::frame] seconds, and each frame
+/// is speech if its center lies within a segment. Overlapping segments are merged.
+///
+/// Returns [Error::EvalConfigError] if the frame length is not positive.
+pub fn evaluate(
+    reference: &[SpeechSegment],
+    hypothesis: &[SpeechSegment],
+    options: &EvalOptions,
+) -> Result<EvalReport, Error> {
+    options.validate()?;
+    let reference = merge(reference);
+    let hypothesis = merge(hypothesis);
+    let duration = options.duration.unwrap_or_else(|| {
+        let end = |segments: &[SpeechSegment]| segments.last().map_or(0.0, |last| last.end);
+        end(&reference).max(end(&hypothesis))
+    });
+
+    // Reference boundaries, surrounded by the collar, are not scored.
+    let mut boundaries: Vec<f64> = reference
+        .iter()
+        .flat_map(|segment| [segment.start, segment.end])
+        .collect();
+    boundaries.sort_by(f64::total_cmp);
+    let collars: Vec<SpeechSegment> = boundaries
+        .iter()
+        .map(|&time| SpeechSegment::new(time - options.collar, time + options.collar))
+        .collect();
+    let collars = merge(&collars);
+
+    let mut in_reference = Cursor {
+        segments: &reference,
+        index: 0,
+    };
+    let mut in_hypothesis = Cursor {
+        segments: &hypothesis,
+        index: 0,
+    };
+    let mut in_collar = Cursor {
+        segments: &collars,
+        index: 0,
+    };
+
+    let (mut true_positive, mut false_positive, mut false_negative, mut true_negative) =
+        (0usize, 0usize, 0usize, 0usize);
+    let frames = (duration / options.frame).ceil() as usize;
+    for frame in 0..frames {
+        let time = (frame as f64 + 0.5) * options.frame;
+        let (speech, detected) = (in_reference.contains(time), in_hypothesis.contains(time));
+        if in_collar.contains(time) {
+            continue;
+        }
+        match (speech, detected) {
+            (true, true) => true_positive += 1,
+            (false, true) => false_positive += 1,
+            (true, false) => false_negative += 1,
+            (false, false) => true_negative += 1,
+        }
+    }
+
+    let seconds = |frames: usize| frames as f64 * options.frame;
+    let speech = seconds(true_positive + false_negative);
+    let non_speech = seconds(false_positive + true_negative);
+    let false_alarm = seconds(false_positive);
+    let missed = seconds(false_negative);
+    let precision = ratio(
+        seconds(true_positive),
+        seconds(true_positive + false_positive),
+    );
+    let recall = ratio(seconds(true_positive), speech);
+
+    let (mut onsets, mut offsets) = (Vec::new(), Vec::new());
+    for segment in &reference {
+        let mut overlapping = hypothesis
+            .iter()
+            .filter(|hypothesis| hypothesis.start < segment.end && hypothesis.end > segment.start);
+        if let Some(first) = overlapping.next() {
+            let last = overlapping.next_back().unwrap_or(first);
+            onsets.push(first.start - segment.start);
+            offsets.push(last.end - segment.end);
+        }
+    }
+
+    Ok(EvalReport {
+        speech,
+        non_speech,
+        false_alarm,
+        missed,
+        precision,
+        recall,
+        f1: ratio(2.0 * precision * recall, precision + recall),
+        false_alarm_rate: ratio(false_alarm, non_speech),
+        miss_rate: ratio(missed, speech),
+        detection_error_rate: ratio(false_alarm + missed, speech),
+        onset_latency: LatencyStats::new(onsets),
+        offset_latency: LatencyStats::new(offsets),
+    })
+}
@@ -0,0 +1,119 @@
+use std::io::{self, Write};
+
+use crate::{Error, SpeechSegment};
+
+/// The label written for each speech segment.
+const LABEL: &str = "speech";
+
+/// Formats seconds as `HH:MM:SS` followed by the separator and milliseconds.
+fn timestamp(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    let (hours, millis) = (millis / 3_600_000, millis % 3_600_000);
+    let (minutes, millis) = (millis / 60_000, millis % 60_000);
+    let (seconds, millis) = (millis / 1000, millis % 1000);
+    format!("{hours:02}:{minutes:02}:{seconds:02}{separator}{millis:03}")
+}
+
+/// Writes speech segments as SubRip (SRT) subtitles.
+pub fn write_srt<W: Write>(mut writer: W, segments: &[SpeechSegment]) -> io::Result<()> {
+    for (i, segment) in segments.iter().enumerate() {
+        let start = timestamp(segment.start, ',');
+        let end = timestamp(segment.end, ',');
+        writeln!(writer, "{}\n{start} --> {end}\n{LABEL}\n", i + 1)?;
+    }
+    Ok(())
+}
+
+/// Writes speech segments as WebVTT subtitles.
+pub fn write_webvtt<W: Write>(mut writer: W, segments: &[SpeechSegment]) -> io::Result<()> {
+    writeln!(writer, "WEBVTT\n")?;
+    for segment in segments {
+        let start = timestamp(segment.start, '.');
+        let end = timestamp(segment.end, '.');
+        writeln!(writer, "{start} --> {end}\n{LABEL}\n")?;
+    }
+    Ok(())
+}
+
+/// Writes speech segments as an Audacity label track.
+pub fn write_audacity<W: Write>(mut writer: W, segments: &[SpeechSegment]) -> io::Result<()> {
+    for segment in segments {
+        writeln!(writer, "{:.6}\t{:.6}\t{LABEL}", segment.start, segment.end)?;
+    }
+    Ok(())
+}
+
+/// Writes speech segments in the NIST Rich Transcription Time Marked (RTTM)
+/// format, as `SPEAKER` lines for the given file id.
+pub fn write_rttm<W: Write>(
+    mut writer: W,
+    segments: &[SpeechSegment],
+    file: &str,
+) -> io::Result<()> {
+    for segment in segments {
+        writeln!(
+            writer,
+            "SPEAKER {file} 1 {:.3} {:.3} <NA> <NA> {LABEL} <NA> <NA>",
+            segment.start,
+            segment.duration()
+        )?;
+    }
+    Ok(())
+}
+
+fn parse_seconds(value: &str, line: usize) -> Result<f64, Error> {
+    value.parse().map_err(|_| Error::ParseError {
+        line,
+        message: format!("`{value}` is not a number of seconds"),
+    })
+}
+
+/// Parses an Audacity label track into segments. Every label is treated as
+/// speech; point labels and frequency lines are skipped.
+pub fn parse_audacity(input: &str) -> Result<Vec<SpeechSegment>, Error> {
+    let mut segments = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut fields = line.split('\t');
+        let (Some(start), Some(end)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        // Spectral selections add a line starting with a backslash.
+        if start.trim().is_empty() || start.starts_with('\\') {
+            continue;
+        }
+
+        let start = parse_seconds(start.trim(), i + 1)?;
+        let end = parse_seconds(end.trim(), i + 1)?;
+        if end > start {
+            segments.push(SpeechSegment::new(start, end));
+        }
+    }
+    Ok(segments)
+}
+
+/// Parses the `SPEAKER` lines of an RTTM file into segments. If a file id is
+/// given, only the lines for that file are returned. Segments are returned in
+/// file order; overlapping speakers are not merged.
+pub fn parse_rttm(input: &str, file: Option<&str>) -> Result<Vec<SpeechSegment>, Error> {
+    let mut segments = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.first() != Some(&"SPEAKER") {
+            continue;
+        }
+        if fields.len() < 5 {
+            return Err(Error::ParseError {
+                line: i + 1,
+                message: "expected at least 5 fields in a SPEAKER line".into(),
+            });
+        }
+        if file.is_some_and(|file| file != fields[1]) {
+            continue;
+        }
+
+        let start = parse_seconds(fields[3], i + 1)?;
+        let duration = parse_seconds(fields[4], i + 1)?;
+        segments.push(SpeechSegment::new(start, start + duration));
+    }
+    Ok(segments)
+}
@@ -0,0 +1,111 @@
+use crate::{LabeledAudio, Sample};
+
+/// Options for gating labeled audio.
+#[derive(Clone, Copy, Debug, typed_builder::TypedBuilder)]
+pub struct GateOptions {
+    /// The gain applied to non-speech, between 0.0 (muted) and 1.0 (unchanged).
+    #[builder(default = 0.0)]
+    pub gain: f32,
+    /// The number of samples to ramp up to full level when speech starts.
+    #[builder(default = 64)]
+    pub attack: usize,
+    /// The number of samples to ramp down to the non-speech gain when speech ends.
+    #[builder(default = 256)]
+    pub release: usize,
+}
+
+impl Default for GateOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The level applied to each sample, ramping between speech and non-speech.
+#[derive(Debug)]
+pub(crate) struct GateState {
+    options: GateOptions,
+    level: Option<f32>,
+}
+
+impl GateState {
+    pub fn new(options: GateOptions) -> Self {
+        Self {
+            options,
+            level: None,
+        }
+    }
+
+    pub fn apply<T: Sample>(&mut self, sample: T, speech: bool) -> T {
+        let GateOptions {
+            gain,
+            attack,
+            release,
+        } = self.options;
+        let target = if speech { 1.0 } else { gain };
+
+        // Start at the level of the first chunk, rather than ramping into it.
+        let level = self.level.get_or_insert(target);
+        let (steps, rising) = if *level < target {
+            (attack, true)
+        } else {
+            (release, false)
+        };
+        let step = (1.0 - gain).abs() / steps.max(1) as f32;
+        *level = match (steps, rising) {
+            (0, _) => target,
+            (_, true) => (*level + step).min(target),
+            (_, false) => (*level - step).max(target),
+        };
+
+        if *level == 1.0 {
+            sample
+        } else {
+            T::from_f32(sample.to_f32() * *level)
+        }
+    }
+}
+
+/// Yields every sample of labeled audio, with non-speech scaled by a gain so
+/// the output has the same length as the input.
+pub struct GateIterator<T, I> {
+    iter: I,
+    chunk: std::vec::IntoIter<T>,
+    speech: bool,
+    state: GateState,
+}
+
+impl<T, I> GateIterator<T, I> {
+    pub(crate) fn new(iter: I, options: GateOptions) -> Self {
+        Self {
+            iter,
+            chunk: Vec::new().into_iter(),
+            speech: false,
+            state: GateState::new(options),
+        }
+    }
+
+    /// Consumes the gate, returning the labeled audio iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<T, I> Iterator for GateIterator<T, I>
+where
+    T: Sample,
+    I: Iterator<Item = LabeledAudio<T>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(sample) = self.chunk.next() {
+                return Some(self.state.apply(sample, self.speech));
+            }
+
+            let audio = self.iter.next()?;
+            self.speech = audio.is_speech();
+            self.chunk = audio.into_iter();
+        }
+    }
+}
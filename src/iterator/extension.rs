@@ -1,14 +1,24 @@
 use crate::label::LabelState;
 use crate::predict::PredictState;
-use crate::{LabelIterator, PredictIterator, Sample, VoiceActivityDetector};
+use crate::{
+    EndpointIterator, EndpointOptions, Endpointer, GateIterator, GateOptions, LabeledAudio,
+    SpeechStats, StatsIterator,
+};
+use crate::{
+    LabelIterator, PredictIterator, Predictor, Sample, TryLabelIterator, TryPredictIterator,
+};
 
 /// Extensions for iterators.
 pub trait IteratorExt: Iterator {
     /// Creates a new [PredictIterator] from an iterator of samples.
-    fn predict(self, vad: &mut VoiceActivityDetector) -> PredictIterator<'_, Self::Item, Self>
+    ///
+    /// The detector may be passed by mutable reference, or by value to create
+    /// an iterator that is `'static`.
+    fn predict<V>(self, vad: V) -> PredictIterator<Self::Item, Self, V>
     where
         Self::Item: Sample,
         Self: Sized,
+        V: Predictor,
     {
         PredictIterator {
             iter: self,
@@ -17,15 +27,19 @@ pub trait IteratorExt: Iterator {
     }
 
     /// Creates a new [LabelIterator] from an iterator of samples.
-    fn label(
+    ///
+    /// The detector may be passed by mutable reference, or by value to create
+    /// an iterator that is `'static`.
+    fn label<V>(
         self,
-        vad: &mut VoiceActivityDetector,
+        vad: V,
         threshold: f32,
         padding_chunks: usize,
-    ) -> LabelIterator<'_, Self::Item, Self>
+    ) -> LabelIterator<Self::Item, Self, V>
     where
         Self::Item: Sample,
         Self: Sized,
+        V: Predictor,
     {
         let state = LabelState::new(threshold, padding_chunks);
         LabelIterator {
@@ -33,6 +47,78 @@ pub trait IteratorExt: Iterator {
             iter: self.predict(vad),
         }
     }
+
+    /// Creates a new [GateIterator] from an iterator of labeled audio, such as
+    /// a [LabelIterator].
+    ///
+    /// Every sample is yielded, with non-speech scaled by the gate's gain, so
+    /// the output lines up with the original audio.
+    fn gate<T>(self, options: GateOptions) -> GateIterator<T, Self>
+    where
+        T: Sample,
+        Self: Iterator<Item = LabeledAudio<T>> + Sized,
+    {
+        GateIterator::new(self, options)
+    }
+
+    /// Creates a new [EndpointIterator] from an iterator of labeled audio, such
+    /// as a [LabelIterator], yielding the endpoint of each turn.
+    fn endpoints<T>(self, sample_rate: u32, options: EndpointOptions) -> EndpointIterator<Self>
+    where
+        Self: Iterator<Item = LabeledAudio<T>> + Sized,
+    {
+        EndpointIterator::new(self, Endpointer::new(sample_rate, options))
+    }
+
+    /// Creates a new [StatsIterator] from an iterator of labeled audio, such as
+    /// a [LabelIterator], accumulating [SpeechStats] as the audio passes through.
+    fn speech_stats<T>(self, sample_rate: u32) -> StatsIterator<Self>
+    where
+        Self: Iterator<Item = LabeledAudio<T>> + Sized,
+    {
+        StatsIterator::new(self, SpeechStats::new(sample_rate))
+    }
 }
 
 impl<I: Iterator> IteratorExt for I {}
+
+/// Extensions for iterators of fallible samples, such as
+/// [hound's samples](https://docs.rs/hound/latest/hound/struct.WavSamples.html).
+///
+/// Unlike filtering the errors out, input errors are passed through as `Err`
+/// items without losing the audio buffered for the current chunk or padding.
+pub trait TryIteratorExt<T, E>: Iterator<Item = Result<T, E>> {
+    /// Creates a new [TryPredictIterator] from an iterator of fallible samples.
+    fn try_predict<V>(self, vad: V) -> TryPredictIterator<T, Self, V>
+    where
+        T: Sample,
+        Self: Sized,
+        V: Predictor,
+    {
+        TryPredictIterator {
+            iter: self,
+            state: PredictState::new(vad),
+        }
+    }
+
+    /// Creates a new [TryLabelIterator] from an iterator of fallible samples.
+    fn try_label<V>(
+        self,
+        vad: V,
+        threshold: f32,
+        padding_chunks: usize,
+    ) -> TryLabelIterator<T, Self, V>
+    where
+        T: Sample,
+        Self: Sized,
+        V: Predictor,
+    {
+        let state = LabelState::new(threshold, padding_chunks);
+        TryLabelIterator {
+            state,
+            iter: self.try_predict(vad),
+        }
+    }
+}
+
+impl<T, E, I: Iterator<Item = Result<T, E>>> TryIteratorExt<T, E> for I {}
@@ -1,20 +1,40 @@
-use crate::label::{LabelState, LabeledAudio};
-use crate::{PredictIterator, Sample};
+use crate::label::{LabelControl, LabelState, LabeledAudio};
+use crate::{PredictIterator, Predictor, Sample, TryPredictIterator};
 
 /// Labels an iterator of speech samples as either speech or non-speech according
 /// to the provided speech sensitity.
-pub struct LabelIterator<'a, T, I>
+pub struct LabelIterator<T, I, V>
 where
     I: Iterator,
 {
-    pub(super) iter: PredictIterator<'a, T, I>,
+    pub(super) iter: PredictIterator<T, I, V>,
     pub(super) state: LabelState<T>,
 }
 
-impl<T, I> Iterator for LabelIterator<'_, T, I>
+impl<T, I, V> LabelIterator<T, I, V>
 where
     T: Sample,
     I: Iterator<Item = T>,
+    V: Predictor,
+{
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.state.control().clone()
+    }
+
+    /// Consumes the iterator, returning the voice activity detector.
+    ///
+    /// Any audio still buffered for padding is discarded.
+    pub fn into_vad(self) -> V {
+        self.iter.into_vad()
+    }
+}
+
+impl<T, I, V> Iterator for LabelIterator<T, I, V>
+where
+    T: Sample,
+    I: Iterator<Item = T>,
+    V: Predictor,
 {
     type Item = LabeledAudio<T>;
 
@@ -32,3 +52,63 @@ where
         self.state.flush()
     }
 }
+
+/// Labels an iterator of fallible speech samples as either speech or
+/// non-speech.
+///
+/// Errors are yielded as soon as they are read, which may be before chunks
+/// that were read earlier but are held back for padding. Labeling continues
+/// with the samples after an error if the consumer keeps iterating.
+pub struct TryLabelIterator<T, I, V>
+where
+    I: Iterator,
+{
+    pub(super) iter: TryPredictIterator<T, I, V>,
+    pub(super) state: LabelState<T>,
+}
+
+impl<T, E, I, V> TryLabelIterator<T, I, V>
+where
+    T: Sample,
+    I: Iterator<Item = Result<T, E>>,
+    V: Predictor,
+{
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.state.control().clone()
+    }
+
+    /// Consumes the iterator, returning the voice activity detector.
+    ///
+    /// Any audio still buffered for padding is discarded.
+    pub fn into_vad(self) -> V {
+        self.iter.into_vad()
+    }
+}
+
+impl<T, E, I, V> Iterator for TryLabelIterator<T, I, V>
+where
+    T: Sample,
+    I: Iterator<Item = Result<T, E>>,
+    V: Predictor,
+{
+    type Item = Result<LabeledAudio<T>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(buffered) = self.state.try_buffer() {
+            return Some(Ok(buffered));
+        }
+
+        for prediction in self.iter.by_ref() {
+            let (chunk, probability) = match prediction {
+                Err(error) => return Some(Err(error)),
+                Ok(value) => value,
+            };
+            if let Some(audio) = self.state.try_next(chunk, probability) {
+                return Some(Ok(audio));
+            }
+        }
+
+        self.state.flush().map(Ok)
+    }
+}
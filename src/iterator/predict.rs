@@ -1,19 +1,37 @@
 use crate::predict::PredictState;
-use crate::Sample;
+use crate::{Predictor, Sample};
 
 /// Predicts speech in an iterator of audio samples.
-pub struct PredictIterator<'a, T, I>
+///
+/// The detector may be any [Predictor], such as an owned, boxed or borrowed
+/// detector or a [BatchedVadHandle](crate::BatchedVadHandle).
+pub struct PredictIterator<T, I, V>
 where
     I: Iterator,
 {
     pub(super) iter: I,
-    pub(super) state: PredictState<'a, T>,
+    pub(super) state: PredictState<T, V>,
 }
 
-impl<T, I> Iterator for PredictIterator<'_, T, I>
+impl<T, I, V> PredictIterator<T, I, V>
 where
     T: Sample,
     I: Iterator<Item = T>,
+    V: Predictor,
+{
+    /// Consumes the iterator, returning the voice activity detector.
+    ///
+    /// Any samples that have not yet filled a complete chunk are discarded.
+    pub fn into_vad(self) -> V {
+        self.state.into_vad()
+    }
+}
+
+impl<T, I, V> Iterator for PredictIterator<T, I, V>
+where
+    T: Sample,
+    I: Iterator<Item = T>,
+    V: Predictor,
 {
     type Item = (Vec<T>, f32);
 
@@ -27,3 +45,55 @@ where
         None
     }
 }
+
+/// Predicts speech in an iterator of fallible audio samples, such as those
+/// decoded from a file.
+///
+/// Errors are yielded as soon as they are read, and the samples after them
+/// continue the current chunk. The consumer decides whether to keep iterating
+/// after an error.
+pub struct TryPredictIterator<T, I, V>
+where
+    I: Iterator,
+{
+    pub(super) iter: I,
+    pub(super) state: PredictState<T, V>,
+}
+
+impl<T, E, I, V> TryPredictIterator<T, I, V>
+where
+    T: Sample,
+    I: Iterator<Item = Result<T, E>>,
+    V: Predictor,
+{
+    /// Consumes the iterator, returning the voice activity detector.
+    ///
+    /// Any samples that have not yet filled a complete chunk are discarded.
+    pub fn into_vad(self) -> V {
+        self.state.into_vad()
+    }
+}
+
+impl<T, E, I, V> Iterator for TryPredictIterator<T, I, V>
+where
+    T: Sample,
+    I: Iterator<Item = Result<T, E>>,
+    V: Predictor,
+{
+    type Item = Result<(Vec<T>, f32), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for sample in self.iter.by_ref() {
+            match sample {
+                Err(error) => return Some(Err(error)),
+                Ok(sample) => {
+                    if let Some(value) = self.state.try_next(sample) {
+                        return Some(Ok(value));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -1,4 +1,6 @@
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Arc;
 
 /// Labels a chunk of audio as either speech or non-speech.
 #[derive(Clone, Debug)]
@@ -20,6 +22,24 @@ impl<T> LabeledAudio<T> {
         }
     }
 
+    /// Returns the audio chunk as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        match &self {
+            LabeledAudio::Speech(audio) => audio,
+            LabeledAudio::NonSpeech(audio) => audio,
+        }
+    }
+
+    /// Returns the number of samples in the audio chunk.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns true if the audio chunk has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
     /// Returns an iterator over the audio chunk slice.
     pub fn iter(&self) -> std::slice::Iter<'_, T> {
         match &self {
@@ -41,8 +61,151 @@ impl<T> IntoIterator for LabeledAudio<T> {
     }
 }
 
+/// A cloneable handle for changing the labeling parameters of a running
+/// labeler, such as a [LabelIterator](crate::LabelIterator).
+///
+/// Changes take effect at the next chunk. Chunks already held back for padding
+/// are kept, so no audio is lost when the padding shrinks.
+///
+/// ```rust
+/// use voice_activity_detector::Labeler;
+///
+/// let mut labeler = Labeler::<i16>::new(0.5, 3);
+/// let control = labeler.control();
+///
+/// // Another thread can hold the control and change the sensitivity.
+/// std::thread::spawn(move || control.set_threshold(0.75)).join().unwrap();
+/// assert_eq!(0.75, labeler.control().threshold());
+/// ```
+#[derive(Clone, Debug)]
+pub struct LabelControl {
+    inner: Arc<LabelControlInner>,
+}
+
+#[derive(Debug)]
+struct LabelControlInner {
+    threshold: AtomicU32,
+    padding_chunks: AtomicUsize,
+}
+
+impl LabelControl {
+    /// Creates a control with the given threshold and padding.
+    pub fn new(threshold: f32, padding_chunks: usize) -> Self {
+        Self {
+            inner: Arc::new(LabelControlInner {
+                threshold: AtomicU32::new(threshold.to_bits()),
+                padding_chunks: AtomicUsize::new(padding_chunks),
+            }),
+        }
+    }
+
+    /// Probabilities greater than or equal to this value are speech.
+    pub fn threshold(&self) -> f32 {
+        f32::from_bits(self.inner.threshold.load(Ordering::Relaxed))
+    }
+
+    /// Sets the threshold used from the next chunk.
+    pub fn set_threshold(&self, threshold: f32) {
+        self.inner
+            .threshold
+            .store(threshold.to_bits(), Ordering::Relaxed);
+    }
+
+    /// The number of chunks labeled as speech before and after speech.
+    pub fn padding_chunks(&self) -> usize {
+        self.inner.padding_chunks.load(Ordering::Relaxed)
+    }
+
+    /// Sets the padding used from the next chunk.
+    pub fn set_padding_chunks(&self, padding_chunks: usize) {
+        self.inner
+            .padding_chunks
+            .store(padding_chunks, Ordering::Relaxed);
+    }
+}
+
+/// Labels chunks of audio from speech probabilities computed elsewhere, such
+/// as a remote inference service, a cache or a different model.
+///
+/// Chunks are labeled with the same padding semantics as
+/// [LabelIterator](crate::LabelIterator). Labels are delayed while chunks are
+/// held back for padding, so each push returns zero or more labeled chunks.
+///
+/// ```rust
+/// use voice_activity_detector::Labeler;
+///
+/// let mut labeler = Labeler::new(0.5, 1);
+/// let mut labels = Vec::new();
+/// for (chunk, probability) in [(vec![0i16; 4], 0.1), (vec![1; 4], 0.9), (vec![0; 4], 0.2)] {
+///     labels.extend(labeler.push(chunk, probability));
+/// }
+/// labels.extend(labeler.finish());
+///
+/// assert_eq!(3, labels.len());
+/// assert!(labels.iter().all(|label| label.is_speech()));
+/// ```
 #[derive(Debug)]
-enum LabelStateInner {
+pub struct Labeler<T> {
+    state: LabelState<T>,
+    output: VecDeque<LabeledAudio<T>>,
+}
+
+impl<T> Labeler<T> {
+    /// Creates a labeler. Probabilities greater than or equal to the threshold
+    /// are speech, and `padding_chunks` chunks before and after speech are also
+    /// labeled as speech.
+    pub fn new(threshold: f32, padding_chunks: usize) -> Self {
+        Self {
+            state: LabelState::new(threshold, padding_chunks),
+            output: VecDeque::new(),
+        }
+    }
+
+    /// Returns a handle for changing the threshold and padding.
+    pub fn control(&self) -> LabelControl {
+        self.state.control().clone()
+    }
+
+    /// Pushes a chunk of audio and its speech probability, returning any
+    /// chunks that could be labeled.
+    pub fn push(
+        &mut self,
+        chunk: Vec<T>,
+        probability: f32,
+    ) -> impl Iterator<Item = LabeledAudio<T>> + '_ {
+        if let Some(audio) = self.state.try_next(chunk, probability) {
+            self.output.push_back(audio);
+        }
+        while let Some(audio) = self.state.try_buffer() {
+            self.output.push_back(audio);
+        }
+
+        self.output.drain(..)
+    }
+
+    pub(crate) fn state(&self) -> &LabelState<T> {
+        &self.state
+    }
+
+    pub(crate) fn state_mut(&mut self) -> &mut LabelState<T> {
+        &mut self.state
+    }
+
+    /// Labels the chunks held back for padding, ending the current audio. The
+    /// labeler can then be used for unrelated audio.
+    pub fn finish(&mut self) -> impl Iterator<Item = LabeledAudio<T>> + '_ {
+        while let Some(audio) = self.state.flush() {
+            self.output.push_back(audio);
+        }
+        self.state.reset();
+
+        self.output.drain(..)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum LabelStateInner {
     /// Waiting for speech to start.
     Idle,
     /// Speech has started, flushes the buffer labeled
@@ -55,8 +218,7 @@ enum LabelStateInner {
 
 #[derive(Debug)]
 pub(crate) struct LabelState<T> {
-    threshold: f32,
-    padding_chunks: usize,
+    control: LabelControl,
     buffer: VecDeque<Vec<T>>,
     state: LabelStateInner,
 }
@@ -64,18 +226,22 @@ pub(crate) struct LabelState<T> {
 impl<T> LabelState<T> {
     pub fn new(threshold: f32, padding_chunks: usize) -> Self {
         Self {
-            threshold,
-            padding_chunks,
+            control: LabelControl::new(threshold, padding_chunks),
             buffer: VecDeque::with_capacity(padding_chunks + 1),
             state: LabelStateInner::Idle,
         }
     }
 
+    /// The handle for the threshold and padding, which are read at each chunk.
+    pub fn control(&self) -> &LabelControl {
+        &self.control
+    }
+
     pub fn try_buffer(&mut self) -> Option<LabeledAudio<T>> {
         match self.state {
             LabelStateInner::Idle => {
                 // If the buffer has grown too large, return the oldest chunk as non-speech.
-                if self.buffer.len() > self.padding_chunks {
+                if self.buffer.len() > self.control.padding_chunks() {
                     if let Some(chunk) = self.buffer.pop_front() {
                         return Some(LabeledAudio::NonSpeech(chunk));
                     }
@@ -115,13 +281,15 @@ impl<T> LabelState<T> {
     }
 
     pub fn try_next(&mut self, chunk: Vec<T>, probability: f32) -> Option<LabeledAudio<T>> {
+        let threshold = self.control.threshold();
+        let padding_chunks = self.control.padding_chunks();
         match self.state {
             LabelStateInner::Idle => {
                 // Add the chunk to the buffer
                 self.buffer.push_back(chunk);
 
                 // If speech has been detected, flush the buffer
-                if probability >= self.threshold {
+                if probability >= threshold {
                     self.state = LabelStateInner::FlushStartPadding;
                     return self
                         .buffer
@@ -131,7 +299,7 @@ impl<T> LabelState<T> {
 
                 // If speech has not yet been detected and the buffer is full,
                 // yield the earliest chunk.
-                if self.buffer.len() > self.padding_chunks {
+                if self.buffer.len() > padding_chunks {
                     return self
                         .buffer
                         .pop_front()
@@ -142,7 +310,7 @@ impl<T> LabelState<T> {
                 None
             }
             LabelStateInner::Active { ref mut speech } => {
-                if probability >= self.threshold {
+                if probability >= threshold {
                     *speech = true;
                     if !self.buffer.is_empty() {
                         self.buffer.push_back(chunk);
@@ -155,7 +323,7 @@ impl<T> LabelState<T> {
                 } else {
                     *speech = false;
                     self.buffer.push_back(chunk);
-                    if self.buffer.len() >= self.padding_chunks {
+                    if self.buffer.len() >= padding_chunks {
                         self.state = LabelStateInner::FlushEndPadding;
                         self.buffer
                             .pop_front()
@@ -170,6 +338,26 @@ impl<T> LabelState<T> {
         }
     }
 
+    /// Returns the chunks held back for padding and the current state.
+    pub fn snapshot(&self) -> (Vec<Vec<T>>, LabelStateInner)
+    where
+        T: Clone,
+    {
+        (self.buffer.iter().cloned().collect(), self.state)
+    }
+
+    /// Restores a state returned by [LabelState::snapshot].
+    pub fn restore(&mut self, buffer: Vec<Vec<T>>, state: LabelStateInner) {
+        self.buffer = buffer.into();
+        self.state = state;
+    }
+
+    /// Discards any buffered chunks and waits for speech to start again.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.state = LabelStateInner::Idle;
+    }
+
     pub fn flush(&mut self) -> Option<LabeledAudio<T>> {
         match self.state {
             LabelStateInner::Idle => self
@@ -1,19 +1,83 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "batch")]
+mod batch;
+mod batched;
+mod compact;
+mod conversation;
+mod endpoint;
 mod error;
+mod eval;
+mod export;
+mod gate;
 mod iterator;
 mod label;
+mod parallel;
+mod pcm;
+mod pool;
 mod predict;
+mod preprocess;
+mod processor;
+mod registry;
 mod sample;
+mod segment;
+mod stats;
 #[cfg(feature = "async")]
 mod stream;
+mod trace;
+mod tune;
 mod vad;
+#[cfg(feature = "wav")]
+mod wav;
 
+#[cfg(feature = "batch")]
+pub use batch::{
+    process_directory, BatchOptions, BatchOptionsBuilder, BatchSummary, ManifestEntry,
+};
+pub use batched::{BatchedVad, BatchedVadBuilder, BatchedVadHandle};
+pub use compact::{
+    compact, CompactOptions, CompactOptionsBuilder, Compacted, OffsetMap, OffsetSegment,
+};
+pub use conversation::{
+    ConversationAnalyzer, ConversationOptions, ConversationOptionsBuilder, ConversationReport,
+    SilenceGap, Speaker, Turn,
+};
+pub use endpoint::{
+    Endpoint, EndpointIterator, EndpointOptions, EndpointOptionsBuilder, Endpointer,
+};
 pub use error::Error;
-pub use iterator::{IteratorExt, LabelIterator, PredictIterator};
-pub use label::LabeledAudio;
+pub use eval::{evaluate, EvalOptions, EvalOptionsBuilder, EvalReport, LatencyStats};
+pub use export::{parse_audacity, parse_rttm, write_audacity, write_rttm, write_srt, write_webvtt};
+pub use gate::{GateIterator, GateOptions, GateOptionsBuilder};
+pub use iterator::{
+    IteratorExt, LabelIterator, PredictIterator, TryIteratorExt, TryLabelIterator,
+    TryPredictIterator,
+};
+pub use label::{LabelControl, LabeledAudio, Labeler};
+pub use parallel::{label_parallel, predict_parallel, ParallelOptions, ParallelOptionsBuilder};
+#[cfg(feature = "async-tokio")]
+pub use pcm::{AsyncPcmReader, AsyncPcmSamples, AsyncPcmWriter};
+pub use pcm::{
+    Endianness, PcmFormat, PcmFormatBuilder, PcmReader, PcmSample, PcmSamples, PcmWriter,
+};
+pub use pool::{Acquire, PoolMetrics, PooledVad, VadPool, VadPoolBuilder};
+pub use predict::Predictor;
+pub use preprocess::{Gain, Preprocessor};
+pub use processor::VadProcessor;
+pub use registry::{RegistryOptions, RegistryOptionsBuilder, RegistrySnapshot, VadRegistry};
 pub use sample::Sample;
+pub use segment::{Segmenter, SpeechSegment};
+pub use stats::{DurationStats, SpeechStats, StatsIterator, StatsSummary};
 #[cfg(feature = "async")]
-pub use stream::{LabelStream, PredictStream, StreamExt};
+pub use stream::{
+    EndpointStream, GateStream, LabelReceiver, LabelSink, LabelStream, PredictStream, StatsStream,
+    StreamExt, TryLabelStream, TryPredictStream, TryStreamExt,
+};
+#[cfg(feature = "async-tokio")]
+pub use stream::{OffloadLabelStream, OffloadPredictStream};
+pub use trace::ProbabilityTrace;
+pub use tune::{tune, CurvePoint, TuneGrid, TuneGridBuilder, TuneObjective, TunePoint, TuneReport};
 pub use vad::{VoiceActivityDetector, VoiceActivityDetectorBuilder};
+#[cfg(feature = "wav")]
+pub use wav::{write_wav_samples, WavLabels, WavOptions, WavOptionsBuilder};
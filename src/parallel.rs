@@ -0,0 +1,165 @@
+use std::sync::Mutex;
+
+use crate::{LabeledAudio, ProbabilityTrace, Sample, VoiceActivityDetector};
+
+/// Options for predicting a long recording in parallel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct ParallelOptions {
+    /// The length of each window predicted by a worker, in seconds.
+    #[builder(default = 600.0)]
+    pub window: f64,
+    /// The audio before each window used to warm up its detector, in seconds.
+    #[builder(default = 5.0)]
+    pub overlap: f64,
+    /// How far from each nominal boundary to search for the quietest chunk to
+    /// split at, in seconds.
+    #[builder(default = 2.0)]
+    pub search: f64,
+    /// The number of worker threads. Defaults to the available parallelism.
+    #[builder(default, setter(strip_option))]
+    pub workers: Option<usize>,
+}
+
+impl Default for ParallelOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Predicts the speech probability of each chunk of a long recording, running
+/// windows of the recording on several threads.
+///
+/// The detector's state depends on all of the audio before it, so the
+/// recording is split at the quietest chunk near each window boundary, and each
+/// window's detector first runs over the overlap before the window to let its
+/// state converge. The probabilities are then joined into a single trace, which
+/// is close to, but not exactly the same as, predicting sequentially.
+///
+/// The given detector is cloned and reset for each window. Samples after the
+/// last complete chunk are ignored.
+pub fn predict_parallel<T>(
+    vad: &VoiceActivityDetector,
+    samples: &[T],
+    options: &ParallelOptions,
+) -> ProbabilityTrace
+where
+    T: Sample + Sync,
+{
+    let chunk_size = vad.chunk_size();
+    let mut trace = ProbabilityTrace::new(vad.sample_rate(), chunk_size);
+    let chunks: Vec<&[T]> = samples.chunks_exact(chunk_size).collect();
+    if chunks.is_empty() {
+        return trace;
+    }
+
+    let to_chunks =
+        |seconds: f64| (seconds * vad.sample_rate() as f64 / chunk_size as f64).round() as usize;
+    let overlap = to_chunks(options.overlap);
+    let windows = windows(
+        &chunks,
+        to_chunks(options.window),
+        to_chunks(options.search),
+    );
+
+    let workers = options
+        .workers
+        .or_else(|| std::thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1)
+        .clamp(1, windows.len());
+    let queue = Mutex::new(windows.iter().copied().enumerate());
+    let mut results = vec![Vec::new(); windows.len()];
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..workers)
+            .map(|_| {
+                let (queue, chunks) = (&queue, &chunks);
+                scope.spawn(move || {
+                    let mut vad = vad.clone();
+                    let mut predicted = Vec::new();
+                    // Take the next window in a closure so the queue is unlocked while it is predicted.
+                    let next = || queue.lock().ok().and_then(|mut windows| windows.next());
+                    while let Some((index, (start, end))) = next() {
+                        vad.reset();
+                        for chunk in &chunks[start.saturating_sub(overlap)..start] {
+                            vad.predict(chunk.iter().copied());
+                        }
+                        let probabilities: Vec<f32> = chunks[start..end]
+                            .iter()
+                            .map(|chunk| vad.predict(chunk.iter().copied()))
+                            .collect();
+                        predicted.push((index, probabilities));
+                    }
+                    predicted
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let predicted = match worker.join() {
+                Ok(predicted) => predicted,
+                Err(panic) => std::panic::resume_unwind(panic),
+            };
+            for (index, probabilities) in predicted {
+                results[index] = probabilities;
+            }
+        }
+    });
+
+    for probabilities in results {
+        trace.probabilities.extend(probabilities);
+    }
+    trace
+}
+
+/// Labels a long recording, predicting windows of it on several threads.
+///
+/// The probabilities from [predict_parallel] are labeled as one timeline, so
+/// padding is applied across window boundaries exactly as when labeling
+/// sequentially.
+pub fn label_parallel<T>(
+    vad: &VoiceActivityDetector,
+    samples: &[T],
+    threshold: f32,
+    padding_chunks: usize,
+    options: &ParallelOptions,
+) -> Vec<LabeledAudio<T>>
+where
+    T: Sample + Sync,
+{
+    let trace = predict_parallel(vad, samples, options);
+    trace
+        .label(threshold, padding_chunks)
+        .into_iter()
+        .zip(samples.chunks_exact(trace.chunk_size))
+        .map(|(speech, chunk)| {
+            let chunk = chunk.to_vec();
+            if speech {
+                LabeledAudio::Speech(chunk)
+            } else {
+                LabeledAudio::NonSpeech(chunk)
+            }
+        })
+        .collect()
+}
+
+/// Splits the chunks into windows of about `window` chunks, moving each
+/// boundary to the quietest chunk within `search` chunks of it.
+fn windows<T: Sample>(chunks: &[&[T]], window: usize, search: usize) -> Vec<(usize, usize)> {
+    let window = window.max(1);
+    let energy = |chunk: &[T]| -> f32 { chunk.iter().map(|s| s.to_f32() * s.to_f32()).sum() };
+
+    let mut windows = Vec::new();
+    let mut start = 0;
+    while start + window < chunks.len() {
+        let nominal = start + window;
+        let low = nominal.saturating_sub(search).max(start + 1);
+        let high = (nominal + search).min(chunks.len() - 1);
+        let end = (low..=high)
+            .min_by(|&a, &b| energy(chunks[a]).total_cmp(&energy(chunks[b])))
+            .unwrap_or(nominal);
+        windows.push((start, end));
+        start = end;
+    }
+    windows.push((start, chunks.len()));
+    windows
+}
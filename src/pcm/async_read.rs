@@ -0,0 +1,140 @@
+use std::io;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use tokio::io::{AsyncRead, ReadBuf};
+
+use super::{PcmFormat, PcmSample};
+use crate::Error;
+
+/// Decodes samples from an asynchronous raw PCM byte stream.
+///
+/// The reader is a stream of `io::Result<T>`. Use [AsyncPcmReader::samples] to
+/// feed the samples into the [StreamExt](crate::StreamExt) adapters, then check
+/// [AsyncPcmReader::take_error] once the samples have been consumed.
+pub struct AsyncPcmReader<R, T> {
+    reader: R,
+    format: PcmFormat,
+    frame: Vec<u8>,
+    filled: usize,
+    error: Option<io::Error>,
+    _sample: PhantomData<fn() -> T>,
+}
+
+impl<R, T> AsyncPcmReader<R, T>
+where
+    R: AsyncRead + Unpin,
+    T: PcmSample,
+{
+    /// Creates a new reader of the given format.
+    ///
+    /// Returns [Error::PcmFormatError] if the format has no channels, or if the
+    /// selected channel does not exist.
+    pub fn new(reader: R, format: PcmFormat) -> Result<Self, Error> {
+        format.validate()?;
+        Ok(Self {
+            reader,
+            frame: vec![0; format.frame_size::<T>()],
+            filled: 0,
+            format,
+            error: None,
+            _sample: PhantomData,
+        })
+    }
+
+    /// Returns the format of the byte stream.
+    pub fn format(&self) -> PcmFormat {
+        self.format
+    }
+
+    /// Returns a stream of samples that ends at the end of the byte stream
+    /// or at the first I/O error. The error is kept, and can be retrieved with
+    /// [AsyncPcmReader::take_error].
+    pub fn samples(&mut self) -> AsyncPcmSamples<'_, R, T> {
+        AsyncPcmSamples { reader: self }
+    }
+
+    /// Takes the I/O error that ended [AsyncPcmReader::samples], if any.
+    pub fn take_error(&mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Consumes the reader, returning the underlying byte stream.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn poll_frame(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<T>>> {
+        while self.filled < self.frame.len() {
+            let mut buffer = ReadBuf::new(&mut self.frame[self.filled..]);
+            match Pin::new(&mut self.reader).poll_read(cx, &mut buffer) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(error)) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(Ok(())) => {}
+            }
+
+            let read = buffer.filled().len();
+            if read == 0 {
+                if self.filled == 0 {
+                    return Poll::Ready(None);
+                }
+                self.filled = 0;
+                return Poll::Ready(Some(Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "the byte stream ended in the middle of a frame",
+                ))));
+            }
+            self.filled += read;
+        }
+
+        self.filled = 0;
+        Poll::Ready(Some(Ok(self.format.decode(&self.frame))))
+    }
+}
+
+impl<R, T> Stream for AsyncPcmReader<R, T>
+where
+    R: AsyncRead + Unpin,
+    T: PcmSample,
+{
+    type Item = io::Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_frame(cx)
+    }
+}
+
+/// A stream of samples decoded by an [AsyncPcmReader]. See [AsyncPcmReader::samples].
+pub struct AsyncPcmSamples<'a, R, T> {
+    reader: &'a mut AsyncPcmReader<R, T>,
+}
+
+impl<R, T> Stream for AsyncPcmSamples<'_, R, T>
+where
+    R: AsyncRead + Unpin,
+    T: PcmSample,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let reader = &mut *self.get_mut().reader;
+        if reader.error.is_some() {
+            return Poll::Ready(None);
+        }
+
+        match reader.poll_frame(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Ready(Some(Ok(sample))) => Poll::Ready(Some(sample)),
+            Poll::Ready(Some(Err(error))) => {
+                reader.error = Some(error);
+                Poll::Ready(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::io;
+use std::marker::PhantomData;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::{PcmFormat, PcmSample};
+use crate::{Error, LabeledAudio};
+
+/// Encodes samples to an asynchronous raw PCM byte stream.
+pub struct AsyncPcmWriter<W, T> {
+    writer: W,
+    format: PcmFormat,
+    bytes: Vec<u8>,
+    _sample: PhantomData<fn() -> T>,
+}
+
+impl<W, T> AsyncPcmWriter<W, T>
+where
+    W: AsyncWrite + Unpin,
+    T: PcmSample,
+{
+    /// Creates a new writer of the given format.
+    ///
+    /// Returns [Error::PcmFormatError] if the format has no channels, or if the
+    /// selected channel does not exist.
+    pub fn new(writer: W, format: PcmFormat) -> Result<Self, Error> {
+        format.validate()?;
+        Ok(Self {
+            writer,
+            format,
+            bytes: Vec::new(),
+            _sample: PhantomData,
+        })
+    }
+
+    /// Writes a slice of samples.
+    pub async fn write_samples(&mut self, samples: &[T]) -> io::Result<()> {
+        let frame_size = self.format.frame_size::<T>();
+        self.bytes.resize(samples.len() * frame_size, 0);
+        for (&sample, frame) in samples.iter().zip(self.bytes.chunks_exact_mut(frame_size)) {
+            self.format.encode(sample, frame);
+        }
+        self.writer.write_all(&self.bytes).await
+    }
+
+    /// Writes the samples of a labeled chunk of audio.
+    pub async fn write_audio(&mut self, audio: &LabeledAudio<T>) -> io::Result<()> {
+        match audio {
+            LabeledAudio::Speech(samples) | LabeledAudio::NonSpeech(samples) => {
+                self.write_samples(samples).await
+            }
+        }
+    }
+
+    /// Flushes the underlying byte stream.
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+
+    /// Consumes the writer, returning the underlying byte stream.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
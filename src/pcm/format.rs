@@ -0,0 +1,108 @@
+use crate::Error;
+
+/// The byte order of raw PCM samples.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first, as produced by `ffmpeg -f s16le`.
+    #[default]
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// Describes the layout of a raw PCM byte stream.
+///
+/// The sample type is chosen by the reader or writer's type parameter. 8-bit
+/// PCM is unsigned, so read it as `u8`, which is centred on 128.
+/// Interleaved multi-channel audio is supported by reading a single channel,
+/// since the voice activity detector only works on mono audio.
+#[derive(Clone, Copy, Debug, typed_builder::TypedBuilder)]
+pub struct PcmFormat {
+    /// The sample rate of the audio.
+    #[builder(setter(into))]
+    pub sample_rate: u32,
+    /// The byte order of each sample.
+    #[builder(default)]
+    pub endianness: Endianness,
+    /// The number of interleaved channels.
+    #[builder(default = 1)]
+    pub channels: u16,
+    /// The channel to read from interleaved audio. When writing, each sample
+    /// is written to every channel.
+    #[builder(default = 0)]
+    pub channel: u16,
+}
+
+/// A [Sample](crate::Sample) that can be decoded from and encoded to raw bytes.
+pub trait PcmSample: crate::Sample {
+    /// The number of bytes in a single encoded sample.
+    const SIZE: usize;
+
+    /// Decodes a sample from exactly [PcmSample::SIZE] bytes.
+    fn from_bytes(bytes: &[u8], endianness: Endianness) -> Self;
+
+    /// Encodes the sample into exactly [PcmSample::SIZE] bytes.
+    fn to_bytes(self, endianness: Endianness, bytes: &mut [u8]);
+}
+
+macro_rules! impl_pcm_sample {
+    ($($ty:ty),*) => {
+        $(
+            impl PcmSample for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn from_bytes(bytes: &[u8], endianness: Endianness) -> Self {
+                    let mut buffer = [0u8; std::mem::size_of::<$ty>()];
+                    buffer.copy_from_slice(bytes);
+                    match endianness {
+                        Endianness::Little => <$ty>::from_le_bytes(buffer),
+                        Endianness::Big => <$ty>::from_be_bytes(buffer),
+                    }
+                }
+
+                fn to_bytes(self, endianness: Endianness, bytes: &mut [u8]) {
+                    let buffer = match endianness {
+                        Endianness::Little => self.to_le_bytes(),
+                        Endianness::Big => self.to_be_bytes(),
+                    };
+                    bytes.copy_from_slice(&buffer);
+                }
+            }
+        )*
+    };
+}
+
+impl_pcm_sample!(f32, i16, i8, u16, u8);
+
+impl PcmFormat {
+    /// Checks that the format has at least one channel, and that the selected
+    /// channel exists.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        // With no channels, even channel 0 is out of range.
+        if self.channel >= self.channels {
+            return Err(Error::PcmFormatError {
+                channels: self.channels,
+                channel: self.channel,
+            });
+        }
+        Ok(())
+    }
+
+    /// The number of bytes in a single frame of interleaved samples.
+    pub(crate) fn frame_size<T: PcmSample>(&self) -> usize {
+        T::SIZE * usize::from(self.channels)
+    }
+
+    /// Decodes the selected channel from a frame of interleaved samples.
+    pub(crate) fn decode<T: PcmSample>(&self, frame: &[u8]) -> T {
+        let offset = T::SIZE * usize::from(self.channel);
+        T::from_bytes(&frame[offset..offset + T::SIZE], self.endianness)
+    }
+
+    /// Encodes a sample into every channel of a frame.
+    pub(crate) fn encode<T: PcmSample>(&self, sample: T, frame: &mut [u8]) {
+        for bytes in frame.chunks_exact_mut(T::SIZE) {
+            sample.to_bytes(self.endianness, bytes);
+        }
+    }
+}
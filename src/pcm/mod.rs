@@ -0,0 +1,15 @@
+#[cfg(feature = "async-tokio")]
+mod async_read;
+#[cfg(feature = "async-tokio")]
+mod async_write;
+mod format;
+mod read;
+mod write;
+
+#[cfg(feature = "async-tokio")]
+pub use async_read::*;
+#[cfg(feature = "async-tokio")]
+pub use async_write::*;
+pub use format::*;
+pub use read::*;
+pub use write::*;
@@ -0,0 +1,125 @@
+use std::io::{self, Read};
+use std::marker::PhantomData;
+
+use super::{PcmFormat, PcmSample};
+use crate::Error;
+
+/// Decodes samples from a raw PCM byte stream.
+///
+/// The reader is an iterator of `io::Result<T>`. Use [PcmReader::samples] to
+/// feed the samples into the [IteratorExt](crate::IteratorExt) adapters, then
+/// check [PcmReader::take_error] once the samples have been consumed.
+///
+/// Each frame is read separately, so wrap unbuffered sources in a [std::io::BufReader].
+pub struct PcmReader<R, T> {
+    reader: R,
+    format: PcmFormat,
+    frame: Vec<u8>,
+    error: Option<io::Error>,
+    _sample: PhantomData<fn() -> T>,
+}
+
+impl<R, T> PcmReader<R, T>
+where
+    R: Read,
+    T: PcmSample,
+{
+    /// Creates a new reader of the given format.
+    ///
+    /// Returns [Error::PcmFormatError] if the format has no channels, or if the
+    /// selected channel does not exist.
+    pub fn new(reader: R, format: PcmFormat) -> Result<Self, Error> {
+        format.validate()?;
+        Ok(Self {
+            reader,
+            frame: vec![0; format.frame_size::<T>()],
+            format,
+            error: None,
+            _sample: PhantomData,
+        })
+    }
+
+    /// Returns the format of the byte stream.
+    pub fn format(&self) -> PcmFormat {
+        self.format
+    }
+
+    /// Returns an iterator of samples that ends at the end of the byte stream
+    /// or at the first I/O error. The error is kept, and can be retrieved with
+    /// [PcmReader::take_error].
+    pub fn samples(&mut self) -> PcmSamples<'_, R, T> {
+        PcmSamples { reader: self }
+    }
+
+    /// Takes the I/O error that ended [PcmReader::samples], if any.
+    pub fn take_error(&mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Consumes the reader, returning the underlying byte stream.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_frame(&mut self) -> Option<io::Result<T>> {
+        let mut filled = 0;
+        while filled < self.frame.len() {
+            match self.reader.read(&mut self.frame[filled..]) {
+                Ok(0) if filled == 0 => return None,
+                Ok(0) => {
+                    return Some(Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "the byte stream ended in the middle of a frame",
+                    )))
+                }
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+
+        Some(Ok(self.format.decode(&self.frame)))
+    }
+}
+
+impl<R, T> Iterator for PcmReader<R, T>
+where
+    R: Read,
+    T: PcmSample,
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_frame()
+    }
+}
+
+/// An iterator of samples decoded by a [PcmReader]. See [PcmReader::samples].
+pub struct PcmSamples<'a, R, T> {
+    reader: &'a mut PcmReader<R, T>,
+}
+
+impl<R, T> Iterator for PcmSamples<'_, R, T>
+where
+    R: Read,
+    T: PcmSample,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.error.is_some() {
+            return None;
+        }
+
+        match self.reader.read_frame()? {
+            Ok(sample) => Some(sample),
+            Err(error) => {
+                self.reader.error = Some(error);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::io::{self, Write};
+use std::marker::PhantomData;
+
+use super::{PcmFormat, PcmSample};
+use crate::{Error, LabeledAudio};
+
+/// Encodes samples to a raw PCM byte stream.
+pub struct PcmWriter<W, T> {
+    writer: W,
+    format: PcmFormat,
+    frame: Vec<u8>,
+    _sample: PhantomData<fn() -> T>,
+}
+
+impl<W, T> PcmWriter<W, T>
+where
+    W: Write,
+    T: PcmSample,
+{
+    /// Creates a new writer of the given format.
+    ///
+    /// Returns [Error::PcmFormatError] if the format has no channels, or if the
+    /// selected channel does not exist.
+    pub fn new(writer: W, format: PcmFormat) -> Result<Self, Error> {
+        format.validate()?;
+        Ok(Self {
+            writer,
+            frame: vec![0; format.frame_size::<T>()],
+            format,
+            _sample: PhantomData,
+        })
+    }
+
+    /// Writes a slice of samples.
+    pub fn write_samples(&mut self, samples: &[T]) -> io::Result<()> {
+        for &sample in samples {
+            self.format.encode(sample, &mut self.frame);
+            self.writer.write_all(&self.frame)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the samples of a labeled chunk of audio.
+    pub fn write_audio(&mut self, audio: &LabeledAudio<T>) -> io::Result<()> {
+        match audio {
+            LabeledAudio::Speech(samples) | LabeledAudio::NonSpeech(samples) => {
+                self.write_samples(samples)
+            }
+        }
+    }
+
+    /// Flushes the underlying byte stream.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Consumes the writer, returning the underlying byte stream.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
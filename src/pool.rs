@@ -0,0 +1,321 @@
+use std::borrow::{Borrow, BorrowMut};
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use ort::session::Session;
+
+use crate::vad::DetectorConfig;
+use crate::{Error, VoiceActivityDetector};
+
+/// A pool of detectors for servers handling many requests at once.
+///
+/// Detectors are created as needed, up to the capacity of the pool, and are
+/// handed out as [PooledVad] guards. Dropping a guard resets the detector and
+/// returns it to the pool. Cloning the pool shares the same detectors.
+///
+/// ```rust,no_run
+/// fn main() -> Result<(), voice_activity_detector::Error> {
+///     use voice_activity_detector::{IteratorExt, VadPool};
+///
+///     let pool = VadPool::builder()
+///         .sample_rate(8000)
+///         .chunk_size(256usize)
+///         .capacity(16usize)
+///         .build()?;
+///
+///     let samples = vec![0i16; 2560];
+///     let vad = pool.acquire();
+///     let speech = samples.into_iter().label(vad, 0.5, 3).filter(|label| label.is_speech()).count();
+///     println!("speech chunks: {speech}, in use: {}", pool.metrics().in_use);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct VadPool {
+    inner: Arc<PoolInner>,
+}
+
+#[derive(Debug)]
+struct PoolInner {
+    config: DetectorConfig,
+    capacity: usize,
+    state: Mutex<PoolState>,
+    available: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct PoolState {
+    idle: Vec<VoiceActivityDetector>,
+    created: usize,
+    waiting: usize,
+    wakers: Vec<Waker>,
+    acquired: u64,
+    waits: u64,
+    wait_time: Duration,
+}
+
+/// A snapshot of the usage of a [VadPool].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolMetrics {
+    /// The maximum number of detectors.
+    pub capacity: usize,
+    /// The number of detectors created so far.
+    pub created: usize,
+    /// The number of detectors currently handed out.
+    pub in_use: usize,
+    /// The number of created detectors waiting in the pool.
+    pub idle: usize,
+    /// The number of callers currently waiting for a detector.
+    pub waiting: usize,
+    /// The total number of detectors handed out.
+    pub acquired: u64,
+    /// The number of acquisitions that had to wait for a detector.
+    pub waits: u64,
+    /// The total time spent waiting for detectors.
+    pub wait_time: Duration,
+}
+
+impl VadPool {
+    /// Create a new [VadPoolBuilder].
+    pub fn builder() -> VadPoolBuilder {
+        VadPoolConfig::builder()
+    }
+
+    /// Takes a detector from the pool, blocking the thread until one is available.
+    pub fn acquire(&self) -> PooledVad {
+        let mut state = self.lock();
+        if let Some(vad) = self.take(&mut state) {
+            return self.guard(vad);
+        }
+
+        let start = Instant::now();
+        state.waiting += 1;
+        let vad = loop {
+            state = self
+                .inner
+                .available
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+            if let Some(vad) = self.take(&mut state) {
+                break vad;
+            }
+        };
+        state.waiting -= 1;
+        state.waits += 1;
+        state.wait_time += start.elapsed();
+        drop(state);
+
+        self.guard(vad)
+    }
+
+    /// Takes a detector from the pool if one is available without waiting.
+    pub fn try_acquire(&self) -> Option<PooledVad> {
+        let mut state = self.lock();
+        let vad = self.take(&mut state)?;
+        drop(state);
+        Some(self.guard(vad))
+    }
+
+    /// Takes a detector from the pool, waiting without blocking the executor
+    /// until one is available.
+    pub fn acquire_async(&self) -> Acquire {
+        Acquire {
+            pool: self.clone(),
+            start: None,
+        }
+    }
+
+    /// Returns a snapshot of the pool's usage.
+    pub fn metrics(&self) -> PoolMetrics {
+        let state = self.lock();
+        PoolMetrics {
+            capacity: self.inner.capacity,
+            created: state.created,
+            in_use: state.created - state.idle.len(),
+            idle: state.idle.len(),
+            waiting: state.waiting,
+            acquired: state.acquired,
+            waits: state.waits,
+            wait_time: state.wait_time,
+        }
+    }
+
+    /// Gets the chunk size of the pooled detectors
+    pub fn chunk_size(&self) -> usize {
+        self.inner.config.chunk_size
+    }
+
+    /// Gets the sample rate of the pooled detectors
+    pub fn sample_rate(&self) -> i64 {
+        self.inner.config.sample_rate
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        // The state is always consistent between operations, so a panic while
+        // holding the lock does not poison the pool.
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Takes an idle detector, or creates one if the pool is not yet full.
+    fn take(&self, state: &mut PoolState) -> Option<VoiceActivityDetector> {
+        let vad = match state.idle.pop() {
+            Some(vad) => vad,
+            None if state.created < self.inner.capacity => {
+                state.created += 1;
+                self.inner.config.detector()
+            }
+            None => return None,
+        };
+        state.acquired += 1;
+        Some(vad)
+    }
+
+    fn guard(&self, vad: VoiceActivityDetector) -> PooledVad {
+        PooledVad {
+            vad: Some(vad),
+            pool: self.clone(),
+        }
+    }
+
+    fn release(&self, mut vad: VoiceActivityDetector) {
+        vad.reset();
+        let mut state = self.lock();
+        state.idle.push(vad);
+        let wakers = std::mem::take(&mut state.wakers);
+        drop(state);
+
+        self.inner.available.notify_one();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// A detector borrowed from a [VadPool], returned to the pool when dropped.
+///
+/// The guard dereferences to the detector, and can be passed by value to the
+/// iterator and stream extensions.
+#[derive(Debug)]
+pub struct PooledVad {
+    vad: Option<VoiceActivityDetector>,
+    pool: VadPool,
+}
+
+impl Deref for PooledVad {
+    type Target = VoiceActivityDetector;
+
+    fn deref(&self) -> &Self::Target {
+        self.vad
+            .as_ref()
+            .expect("the detector was returned to the pool")
+    }
+}
+
+impl DerefMut for PooledVad {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.vad
+            .as_mut()
+            .expect("the detector was returned to the pool")
+    }
+}
+
+impl Borrow<VoiceActivityDetector> for PooledVad {
+    fn borrow(&self) -> &VoiceActivityDetector {
+        self
+    }
+}
+
+impl BorrowMut<VoiceActivityDetector> for PooledVad {
+    fn borrow_mut(&mut self) -> &mut VoiceActivityDetector {
+        self
+    }
+}
+
+impl Drop for PooledVad {
+    fn drop(&mut self) {
+        if let Some(vad) = self.vad.take() {
+            self.pool.release(vad);
+        }
+    }
+}
+
+/// A future waiting for a detector from a [VadPool]. See [VadPool::acquire_async].
+#[derive(Debug)]
+pub struct Acquire {
+    pool: VadPool,
+    start: Option<Instant>,
+}
+
+impl Future for Acquire {
+    type Output = PooledVad;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let pool = self.pool.clone();
+        let mut state = pool.lock();
+        if let Some(vad) = pool.take(&mut state) {
+            if let Some(start) = self.start.take() {
+                state.waiting -= 1;
+                state.waits += 1;
+                state.wait_time += start.elapsed();
+            }
+            drop(state);
+            return Poll::Ready(pool.guard(vad));
+        }
+
+        if self.start.is_none() {
+            self.start = Some(Instant::now());
+            state.waiting += 1;
+        }
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        if self.start.is_some() {
+            self.pool.lock().waiting -= 1;
+        }
+    }
+}
+
+/// The configuration for the [VadPool]. Used to create a [VadPoolBuilder]
+/// that performs runtime validation on build.
+#[derive(Debug, typed_builder::TypedBuilder)]
+#[builder(
+    builder_method(vis = ""),
+    builder_type(name = VadPoolBuilder, vis = "pub"),
+    build_method(into = Result<VadPool, Error>, vis = "pub"))
+]
+struct VadPoolConfig {
+    #[builder(setter(into))]
+    chunk_size: usize,
+    #[builder(setter(into))]
+    sample_rate: i64,
+    #[builder(setter(into))]
+    capacity: usize,
+    #[builder(default, setter(strip_option))]
+    session: Option<Arc<Session>>,
+}
+
+impl From<VadPoolConfig> for Result<VadPool, Error> {
+    fn from(value: VadPoolConfig) -> Self {
+        let config = DetectorConfig::new(value.sample_rate, value.chunk_size, value.session)?;
+
+        Ok(VadPool {
+            inner: Arc::new(PoolInner {
+                config,
+                capacity: value.capacity.max(1),
+                state: Mutex::new(PoolState::default()),
+                available: Condvar::new(),
+            }),
+        })
+    }
+}
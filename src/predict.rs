@@ -1,17 +1,75 @@
+use std::borrow::BorrowMut;
 use std::mem;
 
-use crate::{Sample, VoiceActivityDetector};
+use crate::{BatchedVadHandle, Sample, VoiceActivityDetector};
 
-pub struct PredictState<'a, T> {
-    vad: &'a mut VoiceActivityDetector,
+/// Predicts the probability of speech in chunks of audio, keeping state
+/// between chunks.
+///
+/// The iterator and stream adapters accept any predictor. It is implemented for
+/// anything that mutably borrows a [VoiceActivityDetector], such as an owned,
+/// boxed, borrowed or pooled detector, and for owned or borrowed
+/// [BatchedVadHandle]s. Other predictors can implement it too, and are passed
+/// to the adapters by value and returned by `into_vad`.
+pub trait Predictor {
+    /// Gets the chunk size
+    fn chunk_size(&self) -> usize;
+
+    /// Predicts the existence of speech in a single chunk of audio.
+    ///
+    /// The chunk will be padded if it is too short, or truncated if it is too
+    /// long.
+    fn predict_chunk<S: Sample>(&mut self, chunk: &[S]) -> f32;
+}
+
+impl<V: BorrowMut<VoiceActivityDetector>> Predictor for V {
+    fn chunk_size(&self) -> usize {
+        self.borrow().chunk_size()
+    }
+
+    fn predict_chunk<S: Sample>(&mut self, chunk: &[S]) -> f32 {
+        self.borrow_mut().predict(chunk.iter().copied())
+    }
+}
+
+/// Predicts through the batching thread, as [BatchedVadHandle::predict].
+///
+/// # Panics
+///
+/// Panics if the inference thread has stopped, which only happens when the
+/// model fails, as [VoiceActivityDetector::predict] panics.
+impl Predictor for BatchedVadHandle {
+    fn chunk_size(&self) -> usize {
+        BatchedVadHandle::chunk_size(self)
+    }
+
+    fn predict_chunk<S: Sample>(&mut self, chunk: &[S]) -> f32 {
+        self.predict(chunk.iter().copied())
+            .expect("the batched inference thread stopped")
+    }
+}
+
+impl Predictor for &mut BatchedVadHandle {
+    fn chunk_size(&self) -> usize {
+        BatchedVadHandle::chunk_size(self)
+    }
+
+    fn predict_chunk<S: Sample>(&mut self, chunk: &[S]) -> f32 {
+        (**self).predict_chunk(chunk)
+    }
+}
+
+pub struct PredictState<T, V> {
+    vad: V,
     buffer: Vec<T>,
 }
 
-impl<'a, T> PredictState<'a, T>
+impl<T, V> PredictState<T, V>
 where
     T: Sample,
+    V: Predictor,
 {
-    pub fn new(vad: &'a mut VoiceActivityDetector) -> Self {
+    pub fn new(vad: V) -> Self {
         let chunk_size = vad.chunk_size();
         Self {
             vad,
@@ -21,13 +79,42 @@ where
 
     pub fn try_next(&mut self, sample: T) -> Option<(Vec<T>, f32)> {
         self.buffer.push(sample);
-        if self.buffer.len() < self.vad.chunk_size() {
+        let chunk_size = self.vad.chunk_size();
+        if self.buffer.len() < chunk_size {
             return None;
         }
 
-        let probability = self.vad.predict(self.buffer.iter().copied());
-        let buffer = mem::replace(&mut self.buffer, Vec::with_capacity(self.vad.chunk_size()));
+        let probability = self.vad.predict_chunk(&self.buffer);
+        let buffer = mem::replace(&mut self.buffer, Vec::with_capacity(chunk_size));
 
         Some((buffer, probability))
     }
+
+    /// Discards any partially buffered chunk.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// The samples of the partially buffered chunk.
+    pub fn pending(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// Returns the voice activity detector, discarding any partially buffered chunk.
+    pub fn into_vad(self) -> V {
+        self.vad
+    }
+}
+
+impl<T, V> PredictState<T, V>
+where
+    V: BorrowMut<VoiceActivityDetector>,
+{
+    pub fn vad(&self) -> &VoiceActivityDetector {
+        self.vad.borrow()
+    }
+
+    pub fn vad_mut(&mut self) -> &mut VoiceActivityDetector {
+        self.vad.borrow_mut()
+    }
 }
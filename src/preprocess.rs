@@ -0,0 +1,234 @@
+use std::f32::consts::PI;
+
+/// Conditions audio before it is passed to the model, to make up for poor
+/// microphones and quiet inputs.
+///
+/// The stages run in a fixed order: a DC blocker, a high-pass filter, then gain
+/// normalization. Filter and gain state carries across chunks. The preprocessor
+/// only changes the input to the model; labeled audio always contains the
+/// original samples.
+///
+/// ```rust
+/// use voice_activity_detector::{Gain, Preprocessor};
+///
+/// let mut preprocessor = Preprocessor::new()
+///     .dc_blocker(0.995)
+///     .high_pass(80.0)
+///     .gain(Gain::Rms { target: 0.1, max_gain: 10.0 });
+///
+/// let mut chunk = vec![0.25f32; 512];
+/// preprocessor.process(&mut chunk, 16000);
+/// assert!(chunk[511].abs() < 0.25);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Preprocessor {
+    dc_blocker: Option<DcBlocker>,
+    high_pass: Option<HighPass>,
+    gain: Option<Gain>,
+    agc_gain: f32,
+}
+
+/// How the preprocessor normalizes the level of each chunk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gain {
+    /// Scales each chunk so its RMS level reaches `target`, amplifying by at
+    /// most `max_gain`.
+    Rms {
+        /// The RMS level to reach, between 0.0 and 1.0.
+        target: f32,
+        /// The largest factor the chunk is amplified by.
+        max_gain: f32,
+    },
+    /// Scales each chunk so its peak reaches `target`, amplifying by at most
+    /// `max_gain`.
+    Peak {
+        /// The peak level to reach, between 0.0 and 1.0.
+        target: f32,
+        /// The largest factor the chunk is amplified by.
+        max_gain: f32,
+    },
+    /// Moves a gain shared across chunks towards the one that brings the RMS
+    /// level to `target`, so the level changes smoothly.
+    Agc {
+        /// The RMS level to reach, between 0.0 and 1.0.
+        target: f32,
+        /// The largest factor the audio is amplified by.
+        max_gain: f32,
+        /// The fraction of the way the gain moves towards its goal at each
+        /// chunk, between 0.0 and 1.0.
+        rate: f32,
+    },
+}
+
+/// A first order DC blocking filter.
+#[derive(Clone, Debug)]
+struct DcBlocker {
+    pole: f32,
+    x1: f32,
+    y1: f32,
+}
+
+/// A second order Butterworth high-pass filter.
+#[derive(Clone, Debug)]
+struct HighPass {
+    cutoff: f32,
+    sample_rate: i64,
+    b: [f32; 3],
+    a: [f32; 2],
+    x: [f32; 2],
+    y: [f32; 2],
+}
+
+impl Preprocessor {
+    /// Creates a preprocessor that leaves the audio unchanged.
+    pub fn new() -> Self {
+        Self {
+            dc_blocker: None,
+            high_pass: None,
+            gain: None,
+            agc_gain: 1.0,
+        }
+    }
+
+    /// Removes any constant offset from the audio. The pole sets how quickly
+    /// the offset is tracked; values close to 1.0, such as 0.995, remove only
+    /// the lowest frequencies.
+    pub fn dc_blocker(mut self, pole: f32) -> Self {
+        self.dc_blocker = Some(DcBlocker {
+            pole,
+            x1: 0.0,
+            y1: 0.0,
+        });
+        self
+    }
+
+    /// Removes frequencies below the cutoff in hertz, such as rumble from
+    /// ventilation or handling noise.
+    pub fn high_pass(mut self, cutoff: f32) -> Self {
+        self.high_pass = Some(HighPass {
+            cutoff,
+            sample_rate: 0,
+            b: [1.0, 0.0, 0.0],
+            a: [0.0, 0.0],
+            x: [0.0; 2],
+            y: [0.0; 2],
+        });
+        self
+    }
+
+    /// Normalizes the level of the audio.
+    pub fn gain(mut self, gain: Gain) -> Self {
+        self.gain = Some(gain);
+        self
+    }
+
+    /// Clears the filter and gain state, before processing unrelated audio.
+    pub fn reset(&mut self) {
+        if let Some(dc) = self.dc_blocker.as_mut() {
+            dc.x1 = 0.0;
+            dc.y1 = 0.0;
+        }
+        if let Some(filter) = self.high_pass.as_mut() {
+            filter.x = [0.0; 2];
+            filter.y = [0.0; 2];
+        }
+        self.agc_gain = 1.0;
+    }
+
+    /// Processes a chunk of audio in place.
+    pub fn process(&mut self, samples: &mut [f32], sample_rate: i64) {
+        if let Some(dc) = self.dc_blocker.as_mut() {
+            for sample in samples.iter_mut() {
+                let y = *sample - dc.x1 + dc.pole * dc.y1;
+                dc.x1 = *sample;
+                dc.y1 = y;
+                *sample = y;
+            }
+        }
+
+        if let Some(filter) = self.high_pass.as_mut() {
+            if filter.sample_rate != sample_rate {
+                filter.configure(sample_rate);
+            }
+            for sample in samples.iter_mut() {
+                let x = *sample;
+                let y = filter.b[0] * x + filter.b[1] * filter.x[0] + filter.b[2] * filter.x[1]
+                    - filter.a[0] * filter.y[0]
+                    - filter.a[1] * filter.y[1];
+                filter.x = [x, filter.x[0]];
+                filter.y = [y, filter.y[0]];
+                *sample = y;
+            }
+        }
+
+        let Some(gain) = self.gain else {
+            return;
+        };
+        let factor = match gain {
+            Gain::Rms { target, max_gain } => level_gain(rms(samples), target, max_gain),
+            Gain::Peak { target, max_gain } => level_gain(peak(samples), target, max_gain),
+            Gain::Agc {
+                target,
+                max_gain,
+                rate,
+            } => {
+                let level = rms(samples);
+                if level > 0.0 {
+                    let goal = level_gain(level, target, max_gain);
+                    self.agc_gain += (goal - self.agc_gain) * rate.clamp(0.0, 1.0);
+                }
+                self.agc_gain
+            }
+        };
+        for sample in samples.iter_mut() {
+            *sample = (*sample * factor).clamp(-1.0, 1.0);
+        }
+    }
+}
+
+impl Default for Preprocessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HighPass {
+    /// Computes the filter coefficients for the sample rate.
+    fn configure(&mut self, sample_rate: i64) {
+        let w0 = 2.0 * PI * self.cutoff / sample_rate as f32;
+        let alpha = w0.sin() / (2.0 * std::f32::consts::FRAC_1_SQRT_2);
+        let cos = w0.cos();
+        let a0 = 1.0 + alpha;
+
+        self.b = [
+            (1.0 + cos) / 2.0 / a0,
+            -(1.0 + cos) / a0,
+            (1.0 + cos) / 2.0 / a0,
+        ];
+        self.a = [-2.0 * cos / a0, (1.0 - alpha) / a0];
+        self.sample_rate = sample_rate;
+    }
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f32 = samples.iter().map(|sample| sample * sample).sum();
+    (sum / samples.len() as f32).sqrt()
+}
+
+fn peak(samples: &[f32]) -> f32 {
+    samples
+        .iter()
+        .fold(0.0, |peak, sample| sample.abs().max(peak))
+}
+
+/// The factor that brings the level to the target, without exceeding the maximum.
+fn level_gain(level: f32, target: f32, max_gain: f32) -> f32 {
+    if level <= 0.0 {
+        return 1.0;
+    }
+    (target / level).min(max_gain)
+}
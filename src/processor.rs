@@ -0,0 +1,167 @@
+use std::borrow::BorrowMut;
+use std::collections::VecDeque;
+
+use crate::label::{LabelControl, LabelStateInner, LabeledAudio, Labeler};
+use crate::predict::PredictState;
+use crate::{Error, Sample, VoiceActivityDetector};
+
+/// Labels audio that is pushed in buffers of any size, for use with callback
+/// driven audio APIs.
+///
+/// The processor only buffers samples and runs inference; it does not spawn
+/// threads or require an async runtime. Labeled chunks are produced with the
+/// same padding semantics as [LabelIterator](crate::LabelIterator).
+///
+/// ```rust
+/// fn main() -> Result<(), voice_activity_detector::Error> {
+///     use voice_activity_detector::{VadProcessor, VoiceActivityDetector};
+///
+///     let vad = VoiceActivityDetector::builder()
+///         .sample_rate(8000)
+///         .chunk_size(512usize)
+///         .build()?;
+///     let mut processor = VadProcessor::new(vad, 0.75, 3);
+///
+///     for buffer in [[0i16; 160]; 32] {
+///         for label in processor.push(&buffer) {
+///             println!("speech: {}", label.is_speech());
+///         }
+///     }
+///     for label in processor.finish() {
+///         println!("speech: {}", label.is_speech());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct VadProcessor<T, V = VoiceActivityDetector> {
+    predict: PredictState<T, V>,
+    labeler: Labeler<T>,
+    output: VecDeque<LabeledAudio<T>>,
+}
+
+impl<T, V> VadProcessor<T, V>
+where
+    T: Sample,
+    V: BorrowMut<VoiceActivityDetector>,
+{
+    /// Creates a new processor from an owned or borrowed detector.
+    pub fn new(vad: V, threshold: f32, padding_chunks: usize) -> Self {
+        Self {
+            predict: PredictState::new(vad),
+            labeler: Labeler::new(threshold, padding_chunks),
+            output: VecDeque::new(),
+        }
+    }
+
+    /// Pushes a buffer of samples, returning any chunks that could be labeled.
+    ///
+    /// Samples that do not fill a complete chunk are kept until the next push.
+    pub fn push(&mut self, samples: &[T]) -> impl Iterator<Item = LabeledAudio<T>> + '_ {
+        for &sample in samples {
+            let Some((chunk, probability)) = self.predict.try_next(sample) else {
+                continue;
+            };
+
+            self.output.extend(self.labeler.push(chunk, probability));
+        }
+
+        self.output.drain(..)
+    }
+
+    /// Flushes the chunks buffered for padding, ending the current audio.
+    ///
+    /// Samples that do not fill a complete chunk are discarded. The detector
+    /// state is not reset, so call [VoiceActivityDetector::reset] before
+    /// processing unrelated audio.
+    pub fn finish(&mut self) -> impl Iterator<Item = LabeledAudio<T>> + '_ {
+        self.output.extend(self.labeler.finish());
+        self.predict.clear();
+
+        self.output.drain(..)
+    }
+
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.labeler.control()
+    }
+
+    /// Captures the detector, buffering and padding state, so processing can
+    /// be resumed by another processor.
+    pub(crate) fn snapshot(&self) -> ProcessorSnapshot<T> {
+        let (h, c) = self.predict.vad().lstm_state();
+        let (padding, phase) = self.labeler.state().snapshot();
+        ProcessorSnapshot {
+            chunk_size: self.predict.vad().chunk_size(),
+            h,
+            c,
+            pending: self.predict.pending().to_vec(),
+            padding,
+            phase,
+        }
+    }
+
+    /// Restores the state captured by [VadProcessor::snapshot].
+    ///
+    /// The snapshot is checked against the detector first, so the processor is
+    /// unchanged if it was captured with a different chunk size or is malformed.
+    pub(crate) fn restore(&mut self, snapshot: ProcessorSnapshot<T>) -> Result<(), Error> {
+        let chunk_size = self.predict.vad().chunk_size();
+        let mismatch = if snapshot.chunk_size != chunk_size {
+            Some(format!(
+                "the chunk size {} is not the detector's chunk size {chunk_size}",
+                snapshot.chunk_size
+            ))
+        } else if snapshot.pending.len() >= chunk_size {
+            Some(format!(
+                "the {} pending samples fill a chunk of {chunk_size}",
+                snapshot.pending.len()
+            ))
+        } else if snapshot
+            .padding
+            .iter()
+            .any(|chunk| chunk.len() != chunk_size)
+        {
+            Some(format!("a padding chunk is not {chunk_size} samples long"))
+        } else {
+            None
+        };
+        if let Some(message) = mismatch {
+            return Err(Error::SnapshotError { message });
+        }
+
+        self.predict
+            .vad_mut()
+            .set_lstm_state(&snapshot.h, &snapshot.c)?;
+        self.predict.clear();
+        for &sample in &snapshot.pending {
+            // A partial chunk never fills the buffer, so nothing is predicted.
+            let _ = self.predict.try_next(sample);
+        }
+        self.labeler
+            .state_mut()
+            .restore(snapshot.padding, snapshot.phase);
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the voice activity detector.
+    pub fn vad_mut(&mut self) -> &mut VoiceActivityDetector {
+        self.predict.vad_mut()
+    }
+
+    /// Consumes the processor, returning the voice activity detector.
+    pub fn into_vad(self) -> V {
+        self.predict.into_vad()
+    }
+}
+
+/// The state of a [VadProcessor] between pushes.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct ProcessorSnapshot<T> {
+    chunk_size: usize,
+    h: Vec<f32>,
+    c: Vec<f32>,
+    pending: Vec<T>,
+    padding: Vec<Vec<T>>,
+    phase: LabelStateInner,
+}
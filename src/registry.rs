@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ort::session::Session;
+
+use crate::processor::ProcessorSnapshot;
+use crate::vad::DetectorConfig;
+use crate::{Error, LabeledAudio, Sample, VadProcessor};
+
+/// Options for a [VadRegistry].
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct RegistryOptions {
+    /// The sample rate of every stream.
+    #[builder(setter(into))]
+    pub sample_rate: i64,
+    /// The chunk size for the detectors.
+    #[builder(setter(into))]
+    pub chunk_size: usize,
+    /// Probabilities greater than or equal to this value are considered speech.
+    #[builder(default = 0.5)]
+    pub threshold: f32,
+    /// The number of chunks to add to the start and end of speech.
+    #[builder(default = 3)]
+    pub padding_chunks: usize,
+    /// Streams that receive no samples for this long are evicted.
+    #[builder(default = Duration::from_secs(60))]
+    pub ttl: Duration,
+    /// The session shared by the detectors. Defaults to the bundled model.
+    #[builder(default, setter(strip_option))]
+    pub session: Option<Arc<Session>>,
+}
+
+/// A callback receiving the key and final labels of an evicted stream.
+type EvictCallback<K, T> = Box<dyn FnMut(K, Vec<LabeledAudio<T>>) + Send>;
+
+/// Labels many independent audio streams, keyed by an identifier such as a
+/// call ID.
+///
+/// A detector and labeling state are created for a key the first time samples
+/// are pushed for it. Streams that receive no samples for longer than the TTL
+/// are evicted, passing their remaining labels to the [VadRegistry::on_evict]
+/// callback. Idle streams are checked at most once per TTL while pushing, or
+/// whenever [VadRegistry::evict_idle] is called.
+///
+/// ```rust,no_run
+/// fn main() -> Result<(), voice_activity_detector::Error> {
+///     use voice_activity_detector::{RegistryOptions, VadRegistry};
+///
+///     let options = RegistryOptions::builder()
+///         .sample_rate(8000)
+///         .chunk_size(256usize)
+///         .build();
+///     let mut registry = VadRegistry::<String, i16>::new(options)?;
+///     registry.on_evict(|call, labels| println!("{call} ended with {} chunks", labels.len()));
+///
+///     for label in registry.push("call-1".into(), &[0i16; 512]) {
+///         println!("speech: {}", label.is_speech());
+///     }
+///     registry.finish(&"call-1".to_string());
+///     Ok(())
+/// }
+/// ```
+pub struct VadRegistry<K, T> {
+    options: RegistryOptions,
+    config: DetectorConfig,
+    streams: HashMap<K, RegistryStream<T>>,
+    on_evict: Option<EvictCallback<K, T>>,
+    last_sweep: Instant,
+}
+
+struct RegistryStream<T> {
+    processor: VadProcessor<T>,
+    last_seen: Instant,
+}
+
+/// The state of every stream in a [VadRegistry], including the detector state,
+/// partial chunks and audio held back for padding.
+///
+/// With the `serde` feature, snapshots can be serialized so the streams
+/// survive a restart.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegistrySnapshot<K, T> {
+    streams: Vec<(K, ProcessorSnapshot<T>)>,
+}
+
+impl<K, T> RegistrySnapshot<K, T> {
+    /// Returns the number of streams in the snapshot.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns true if the snapshot has no streams.
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl<K, T> VadRegistry<K, T>
+where
+    K: Eq + Hash + Clone,
+    T: Sample,
+{
+    /// Creates an empty registry.
+    pub fn new(options: RegistryOptions) -> Result<Self, Error> {
+        let config = DetectorConfig::new(
+            options.sample_rate,
+            options.chunk_size,
+            options.session.clone(),
+        )?;
+
+        Ok(Self {
+            options,
+            config,
+            streams: HashMap::new(),
+            on_evict: None,
+            last_sweep: Instant::now(),
+        })
+    }
+
+    /// Sets the callback receiving the key and final labels of evicted streams.
+    pub fn on_evict<F>(&mut self, callback: F)
+    where
+        F: FnMut(K, Vec<LabeledAudio<T>>) + Send + 'static,
+    {
+        self.on_evict = Some(Box::new(callback));
+    }
+
+    /// Pushes samples for a stream, creating it if needed, and returns any
+    /// chunks that could be labeled.
+    pub fn push(&mut self, key: K, samples: &[T]) -> Vec<LabeledAudio<T>> {
+        // Refresh the stream first, so the sweep never evicts it.
+        if let Some(stream) = self.streams.get_mut(&key) {
+            stream.last_seen = Instant::now();
+        }
+        if self.last_sweep.elapsed() >= self.options.ttl {
+            self.evict_idle();
+        }
+
+        let stream = self.streams.entry(key).or_insert_with(|| RegistryStream {
+            processor: new_processor(&self.options, &self.config),
+            last_seen: Instant::now(),
+        });
+        stream.processor.push(samples).collect()
+    }
+
+    /// Removes a stream, returning the chunks held back for padding.
+    pub fn finish(&mut self, key: &K) -> Option<Vec<LabeledAudio<T>>> {
+        let mut stream = self.streams.remove(key)?;
+        Some(stream.processor.finish().collect())
+    }
+
+    /// Evicts every stream idle for longer than the TTL, passing its final
+    /// labels to the eviction callback. Returns the number of evicted streams.
+    pub fn evict_idle(&mut self) -> usize {
+        self.last_sweep = Instant::now();
+        let ttl = self.options.ttl;
+        let idle: Vec<K> = self
+            .streams
+            .iter()
+            .filter(|(_, stream)| stream.last_seen.elapsed() > ttl)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in &idle {
+            let Some((key, mut stream)) = self.streams.remove_entry(key) else {
+                continue;
+            };
+            let labels = stream.processor.finish().collect();
+            if let Some(callback) = self.on_evict.as_mut() {
+                callback(key, labels);
+            }
+        }
+
+        idle.len()
+    }
+
+    /// Returns true if the registry has a stream for the key.
+    pub fn contains(&self, key: &K) -> bool {
+        self.streams.contains_key(key)
+    }
+
+    /// Returns the number of streams.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns true if the registry has no streams.
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+
+    /// Captures the state of every stream.
+    pub fn snapshot(&self) -> RegistrySnapshot<K, T> {
+        let streams = self
+            .streams
+            .iter()
+            .map(|(key, stream)| (key.clone(), stream.processor.snapshot()))
+            .collect();
+        RegistrySnapshot { streams }
+    }
+
+    /// Restores the streams of a snapshot, replacing any streams with the same
+    /// keys. Restored streams are considered active from now.
+    ///
+    /// Returns [Error::SnapshotError], restoring no streams, if any stream was
+    /// captured with a different chunk size or has a malformed state.
+    pub fn restore(&mut self, snapshot: RegistrySnapshot<K, T>) -> Result<(), Error> {
+        let streams = snapshot
+            .streams
+            .into_iter()
+            .map(|(key, state)| {
+                let mut processor = new_processor(&self.options, &self.config);
+                processor.restore(state)?;
+                let stream = RegistryStream {
+                    processor,
+                    last_seen: Instant::now(),
+                };
+                Ok((key, stream))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.streams.extend(streams);
+        Ok(())
+    }
+}
+
+fn new_processor<T: Sample>(options: &RegistryOptions, config: &DetectorConfig) -> VadProcessor<T> {
+    VadProcessor::new(config.detector(), options.threshold, options.padding_chunks)
+}
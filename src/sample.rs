@@ -1,38 +1,71 @@
 /// A single sample of Linear Pulse Code Modulation (LPCM) encoded audio.
 ///
-/// Integers are between a range of -32768 to 32768.
+/// Integers are between a range of -32768 to 32768, except `u8`, which is
+/// unsigned 8-bit PCM centred on 128.
 /// Floats are between -1.0 and 1.0.
 pub trait Sample: Copy + Default + Sized {
     /// Convert the sample to a float.
     fn to_f32(self) -> f32;
+
+    /// Convert a float to the sample, the inverse of [Sample::to_f32]. Values
+    /// outside of the sample's range are clamped.
+    fn from_f32(value: f32) -> Self;
 }
 
 impl Sample for f32 {
     fn to_f32(self) -> f32 {
         self
     }
+
+    fn from_f32(value: f32) -> Self {
+        value
+    }
 }
 
 impl Sample for i16 {
     fn to_f32(self) -> f32 {
         f32::from(self) / 32768.0
     }
+
+    fn from_f32(value: f32) -> Self {
+        (value * 32768.0)
+            .round()
+            .clamp(i16::MIN.into(), i16::MAX.into()) as i16
+    }
 }
 
 impl Sample for i8 {
     fn to_f32(self) -> f32 {
         f32::from(self) / 32768.0
     }
+
+    fn from_f32(value: f32) -> Self {
+        (value * 32768.0)
+            .round()
+            .clamp(i8::MIN.into(), i8::MAX.into()) as i8
+    }
 }
 
 impl Sample for u16 {
     fn to_f32(self) -> f32 {
         f32::from(self) / 32768.0
     }
+
+    fn from_f32(value: f32) -> Self {
+        (value * 32768.0)
+            .round()
+            .clamp(u16::MIN.into(), u16::MAX.into()) as u16
+    }
 }
 
 impl Sample for u8 {
     fn to_f32(self) -> f32 {
-        f32::from(self) / 32768.0
+        (f32::from(self) - 128.0) / 128.0
+    }
+
+    fn from_f32(value: f32) -> Self {
+        (value * 128.0 + 128.0)
+            .round()
+            .clamp(u8::MIN.into(), u8::MAX.into()) as u8
     }
 }
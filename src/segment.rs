@@ -0,0 +1,130 @@
+use crate::LabeledAudio;
+
+/// A span of speech, in seconds from the start of the audio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeechSegment {
+    /// The start of the speech, in seconds.
+    pub start: f64,
+    /// The end of the speech, in seconds.
+    pub end: f64,
+}
+
+impl SpeechSegment {
+    /// Creates a new segment from a start and end time in seconds.
+    pub fn new(start: f64, end: f64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates a new segment from a start and end sample offset.
+    pub fn from_samples(start: usize, end: usize, sample_rate: u32) -> Self {
+        let sample_rate = f64::from(sample_rate);
+        Self {
+            start: start as f64 / sample_rate,
+            end: end as f64 / sample_rate,
+        }
+    }
+
+    /// Collects the speech segments from a sequence of labeled audio.
+    pub fn from_labels<'a, T, I>(labels: I, sample_rate: u32) -> Vec<SpeechSegment>
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a LabeledAudio<T>>,
+    {
+        let mut segmenter = Segmenter::new(sample_rate);
+        let mut segments: Vec<SpeechSegment> = labels
+            .into_iter()
+            .filter_map(|audio| segmenter.push(audio))
+            .collect();
+        segments.extend(segmenter.finish());
+        segments
+    }
+
+    /// The length of the segment in seconds.
+    pub fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+}
+
+/// Groups consecutive speech chunks of [LabeledAudio] into [SpeechSegment]s.
+///
+/// ```rust
+/// use voice_activity_detector::{LabeledAudio, Segmenter, SpeechSegment};
+///
+/// let labels = [
+///     LabeledAudio::NonSpeech(vec![0i16; 8000]),
+///     LabeledAudio::Speech(vec![0i16; 4000]),
+///     LabeledAudio::Speech(vec![0i16; 4000]),
+/// ];
+///
+/// let mut segmenter = Segmenter::new(8000);
+/// let mut segments: Vec<SpeechSegment> = labels
+///     .iter()
+///     .filter_map(|audio| segmenter.push(audio))
+///     .collect();
+/// segments.extend(segmenter.finish());
+///
+/// assert_eq!(segments, vec![SpeechSegment::new(1.0, 2.0)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Segmenter {
+    sample_rate: u32,
+    min_duration: f64,
+    position: usize,
+    start: Option<usize>,
+}
+
+impl Segmenter {
+    /// Creates a new segmenter for audio of the given sample rate.
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            min_duration: 0.0,
+            position: 0,
+            start: None,
+        }
+    }
+
+    /// Discards segments shorter than the given number of seconds.
+    pub fn with_min_duration(mut self, seconds: f64) -> Self {
+        self.min_duration = seconds;
+        self
+    }
+
+    /// The number of samples pushed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Pushes the next labeled chunk, returning a segment if speech just ended.
+    pub fn push<T>(&mut self, audio: &LabeledAudio<T>) -> Option<SpeechSegment> {
+        self.push_label(audio.is_speech(), audio.len())
+    }
+
+    /// Pushes the label of the next `len` samples, for callers that track
+    /// labels without the audio.
+    pub(crate) fn push_label(&mut self, speech: bool, len: usize) -> Option<SpeechSegment> {
+        let start = self.position;
+        self.position += len;
+        match (speech, self.start) {
+            (true, None) => {
+                self.start = Some(start);
+                None
+            }
+            (false, Some(_)) => self.end_at(start),
+            _ => None,
+        }
+    }
+
+    /// Ends the audio, returning a segment if it ended in speech.
+    pub fn finish(&mut self) -> Option<SpeechSegment> {
+        self.end_at(self.position)
+    }
+
+    fn end_at(&mut self, end: usize) -> Option<SpeechSegment> {
+        self.start
+            .take()
+            .map(|start| SpeechSegment::from_samples(start, end, self.sample_rate))
+            .filter(|segment| segment.duration() >= self.min_duration)
+    }
+}
@@ -0,0 +1,245 @@
+use crate::LabeledAudio;
+
+/// The number of bins in the probability histogram, each `0.1` wide.
+const HISTOGRAM_BINS: usize = 10;
+
+/// Summary statistics for a set of durations, in seconds.
+///
+/// Percentiles use the nearest-rank method.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DurationStats {
+    /// The number of durations measured.
+    pub count: usize,
+    /// The mean duration.
+    pub mean: f64,
+    /// The shortest duration.
+    pub min: f64,
+    /// The longest duration.
+    pub max: f64,
+    /// The median duration.
+    pub p50: f64,
+    /// The 90th percentile.
+    pub p90: f64,
+    /// The 95th percentile.
+    pub p95: f64,
+    /// The 99th percentile.
+    pub p99: f64,
+}
+
+impl DurationStats {
+    fn new(mut durations: Vec<f64>) -> Self {
+        if durations.is_empty() {
+            return Self::default();
+        }
+
+        durations.sort_by(f64::total_cmp);
+        let count = durations.len();
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * count as f64).ceil() as usize;
+            durations[rank.clamp(1, count) - 1]
+        };
+
+        Self {
+            count,
+            mean: durations.iter().sum::<f64>() / count as f64,
+            min: durations[0],
+            max: durations[count - 1],
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+        }
+    }
+}
+
+/// A summary of the speech in a recording or stream.
+///
+/// Ratios and means with an empty denominator are reported as `0.0`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatsSummary {
+    /// Seconds of audio labeled as speech.
+    pub speech_duration: f64,
+    /// Seconds of audio labeled as non-speech.
+    pub non_speech_duration: f64,
+    /// The fraction of the audio labeled as speech.
+    pub speech_ratio: f64,
+    /// The number of utterances, counting one still in progress.
+    pub utterances: usize,
+    /// The lengths of the utterances.
+    pub utterance_lengths: DurationStats,
+    /// The lengths of the pauses between utterances. Silence before the first
+    /// utterance or after the last one is not a pause.
+    pub pause_lengths: DurationStats,
+    /// The mean of the recorded probabilities.
+    pub mean_probability: f64,
+    /// The number of recorded probabilities in each bin of width `0.1`, from
+    /// `[0.0, 0.1)` to `[0.9, 1.0]`.
+    pub probability_histogram: Vec<usize>,
+}
+
+/// Accumulates speech statistics from [LabeledAudio], such as the output of a
+/// [LabelIterator](crate::LabelIterator) or [LabelStream](crate::LabelStream).
+///
+/// The statistics update with every chunk, so [SpeechStats::summary] can be
+/// called at any time for a live view, or once at the end for a final summary.
+/// Labeled audio does not carry probabilities, so they are recorded separately
+/// with [SpeechStats::push_probability], for example from a
+/// [PredictIterator](crate::PredictIterator).
+///
+/// ```rust
+/// use voice_activity_detector::{LabeledAudio, SpeechStats};
+///
+/// let mut stats = SpeechStats::new(8000);
+/// stats.push(&LabeledAudio::NonSpeech(vec![0i16; 8000]));
+/// stats.push(&LabeledAudio::Speech(vec![0i16; 16000]));
+/// stats.push(&LabeledAudio::NonSpeech(vec![0i16; 8000]));
+///
+/// let summary = stats.summary();
+/// assert_eq!(summary.speech_duration, 2.0);
+/// assert_eq!(summary.speech_ratio, 0.5);
+/// assert_eq!(summary.utterances, 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpeechStats {
+    sample_rate: u32,
+    speech: usize,
+    non_speech: usize,
+    utterances: Vec<usize>,
+    pauses: Vec<usize>,
+    run: Option<(bool, usize)>,
+    probabilities: usize,
+    probability_sum: f64,
+    histogram: [usize; HISTOGRAM_BINS],
+}
+
+impl SpeechStats {
+    /// Creates empty statistics for audio of the given sample rate.
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            speech: 0,
+            non_speech: 0,
+            utterances: Vec::new(),
+            pauses: Vec::new(),
+            run: None,
+            probabilities: 0,
+            probability_sum: 0.0,
+            histogram: [0; HISTOGRAM_BINS],
+        }
+    }
+
+    /// Records the next labeled chunk.
+    pub fn push<T>(&mut self, audio: &LabeledAudio<T>) {
+        let speech = audio.is_speech();
+        let len = audio.len();
+        if speech {
+            self.speech += len;
+        } else {
+            self.non_speech += len;
+        }
+
+        match self.run {
+            Some((run_speech, ref mut run_len)) if run_speech == speech => *run_len += len,
+            previous => {
+                self.end_run(previous);
+                self.run = Some((speech, len));
+            }
+        }
+    }
+
+    /// Records the probability of a chunk.
+    pub fn push_probability(&mut self, probability: f32) {
+        let probability = probability.clamp(0.0, 1.0);
+        let bin = ((probability * HISTOGRAM_BINS as f32) as usize).min(HISTOGRAM_BINS - 1);
+        self.histogram[bin] += 1;
+        self.probabilities += 1;
+        self.probability_sum += f64::from(probability);
+    }
+
+    /// Clears the statistics.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.sample_rate);
+    }
+
+    /// Summarizes the audio recorded so far.
+    pub fn summary(&self) -> StatsSummary {
+        let seconds = |samples: usize| samples as f64 / f64::from(self.sample_rate);
+        let mut utterances: Vec<f64> = self.utterances.iter().map(|&len| seconds(len)).collect();
+        if let Some((true, len)) = self.run {
+            utterances.push(seconds(len));
+        }
+        let pauses: Vec<f64> = self.pauses.iter().map(|&len| seconds(len)).collect();
+
+        let total = self.speech + self.non_speech;
+        StatsSummary {
+            speech_duration: seconds(self.speech),
+            non_speech_duration: seconds(self.non_speech),
+            speech_ratio: if total > 0 {
+                self.speech as f64 / total as f64
+            } else {
+                0.0
+            },
+            utterances: utterances.len(),
+            utterance_lengths: DurationStats::new(utterances),
+            pause_lengths: DurationStats::new(pauses),
+            mean_probability: if self.probabilities > 0 {
+                self.probability_sum / self.probabilities as f64
+            } else {
+                0.0
+            },
+            probability_histogram: self.histogram.to_vec(),
+        }
+    }
+
+    fn end_run(&mut self, run: Option<(bool, usize)>) {
+        match run {
+            Some((true, len)) => self.utterances.push(len),
+            // Only silence that follows an utterance can become a pause, once the
+            // next utterance starts.
+            Some((false, len)) if !self.utterances.is_empty() => self.pauses.push(len),
+            _ => {}
+        }
+    }
+}
+
+/// Passes labeled audio through unchanged, accumulating [SpeechStats].
+pub struct StatsIterator<I> {
+    iter: I,
+    stats: SpeechStats,
+}
+
+impl<I> StatsIterator<I> {
+    pub(crate) fn new(iter: I, stats: SpeechStats) -> Self {
+        Self { iter, stats }
+    }
+
+    /// Returns the statistics of the audio yielded so far.
+    pub fn stats(&self) -> &SpeechStats {
+        &self.stats
+    }
+
+    /// Consumes the iterator, returning the statistics.
+    pub fn into_stats(self) -> SpeechStats {
+        self.stats
+    }
+
+    /// Consumes the iterator, returning the labeled audio iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<T, I> Iterator for StatsIterator<I>
+where
+    I: Iterator<Item = LabeledAudio<T>>,
+{
+    type Item = LabeledAudio<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let audio = self.iter.next()?;
+        self.stats.push(&audio);
+        Some(audio)
+    }
+}
@@ -0,0 +1,64 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use pin_project::pin_project;
+
+use crate::{Endpoint, Endpointer, LabeledAudio};
+
+/// Yields the [Endpoint] of each turn in a stream of labeled audio.
+#[pin_project]
+pub struct EndpointStream<St> {
+    #[pin]
+    stream: St,
+    endpointer: Endpointer,
+    done: bool,
+}
+
+impl<St> EndpointStream<St> {
+    pub(super) fn new(stream: St, endpointer: Endpointer) -> Self {
+        Self {
+            stream,
+            endpointer,
+            done: false,
+        }
+    }
+
+    /// Returns the endpointer, to start a new turn or check the position.
+    pub fn endpointer_mut(&mut self) -> &mut Endpointer {
+        &mut self.endpointer
+    }
+
+    /// Consumes the stream, returning the labeled audio stream.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<T, St> Stream for EndpointStream<St>
+where
+    St: Stream<Item = LabeledAudio<T>>,
+{
+    type Item = Endpoint;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let audio = match this.stream.as_mut().poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    return Poll::Ready(this.endpointer.finish());
+                }
+                Poll::Ready(Some(audio)) => audio,
+            };
+            if let Some(endpoint) = this.endpointer.push(&audio) {
+                return Poll::Ready(Some(endpoint));
+            }
+        }
+    }
+}
@@ -2,15 +2,25 @@ use futures::Stream;
 
 use crate::label::LabelState;
 use crate::predict::PredictState;
-use crate::{LabelStream, PredictStream, Sample, VoiceActivityDetector};
+use crate::{
+    EndpointOptions, EndpointStream, Endpointer, GateOptions, GateStream, LabeledAudio,
+    SpeechStats, StatsStream,
+};
+#[cfg(feature = "async-tokio")]
+use crate::{Error, OffloadLabelStream, OffloadPredictStream};
+use crate::{LabelStream, PredictStream, Predictor, Sample, TryLabelStream, TryPredictStream};
 
 /// Extensions for streams.
 pub trait StreamExt: Stream {
     /// Creates a new [PredictStream] from a stream of samples.
-    fn predict(self, vad: &mut VoiceActivityDetector) -> PredictStream<'_, Self::Item, Self>
+    ///
+    /// The detector may be passed by mutable reference, or by value to create
+    /// a stream that is `'static` and can be moved into a spawned task.
+    fn predict<V>(self, vad: V) -> PredictStream<Self::Item, Self, V>
     where
         Self::Item: Sample,
         Self: Sized,
+        V: Predictor,
     {
         PredictStream {
             stream: self,
@@ -19,15 +29,19 @@ pub trait StreamExt: Stream {
     }
 
     /// Creates a new [LabelStream] from an iterator of samples.
-    fn label(
+    ///
+    /// The detector may be passed by mutable reference, or by value to create
+    /// a stream that is `'static` and can be moved into a spawned task.
+    fn label<V>(
         self,
-        vad: &mut VoiceActivityDetector,
+        vad: V,
         threshold: f32,
         padding_chunks: usize,
-    ) -> LabelStream<'_, Self::Item, Self>
+    ) -> LabelStream<Self::Item, Self, V>
     where
         Self::Item: Sample,
         Self: Sized,
+        V: Predictor,
     {
         let state = LabelState::new(threshold, padding_chunks);
         LabelStream {
@@ -35,6 +49,123 @@ pub trait StreamExt: Stream {
             stream: self.predict(vad),
         }
     }
+
+    /// Creates a new [GateStream] from a stream of labeled audio, such as a
+    /// [LabelStream].
+    ///
+    /// Every sample is yielded, with non-speech scaled by the gate's gain, so
+    /// the output lines up with the original audio.
+    fn gate<T>(self, options: GateOptions) -> GateStream<T, Self>
+    where
+        T: Sample,
+        Self: Stream<Item = LabeledAudio<T>> + Sized,
+    {
+        GateStream::new(self, options)
+    }
+
+    /// Creates a new [EndpointStream] from a stream of labeled audio, such as a
+    /// [LabelStream], yielding the endpoint of each turn.
+    fn endpoints<T>(self, sample_rate: u32, options: EndpointOptions) -> EndpointStream<Self>
+    where
+        Self: Stream<Item = LabeledAudio<T>> + Sized,
+    {
+        EndpointStream::new(self, Endpointer::new(sample_rate, options))
+    }
+
+    /// Creates a new [StatsStream] from a stream of labeled audio, such as a
+    /// [LabelStream], accumulating [SpeechStats] as the audio passes through.
+    fn speech_stats<T>(self, sample_rate: u32) -> StatsStream<Self>
+    where
+        Self: Stream<Item = LabeledAudio<T>> + Sized,
+    {
+        StatsStream::new(self, SpeechStats::new(sample_rate))
+    }
+
+    /// Creates a new [OffloadPredictStream] from a stream of samples.
+    ///
+    /// Inference runs on a dedicated thread, so polling the stream never blocks
+    /// the executor. At most `max_in_flight` chunks are queued for inference.
+    /// Returns an error if the inference thread cannot be spawned.
+    #[cfg(feature = "async-tokio")]
+    fn predict_offload<V>(
+        self,
+        vad: V,
+        max_in_flight: usize,
+    ) -> Result<OffloadPredictStream<Self::Item, Self, V>, Error>
+    where
+        Self::Item: Sample + Send + 'static,
+        Self: Sized,
+        V: Predictor + Send + 'static,
+    {
+        OffloadPredictStream::new(self, vad, max_in_flight)
+    }
+
+    /// Creates a new [OffloadLabelStream] from a stream of samples.
+    ///
+    /// Inference runs on a dedicated thread, so polling the stream never blocks
+    /// the executor. At most `max_in_flight` chunks are queued for inference.
+    /// Returns an error if the inference thread cannot be spawned.
+    #[cfg(feature = "async-tokio")]
+    fn label_offload<V>(
+        self,
+        vad: V,
+        threshold: f32,
+        padding_chunks: usize,
+        max_in_flight: usize,
+    ) -> Result<OffloadLabelStream<Self::Item, Self, V>, Error>
+    where
+        Self::Item: Sample + Send + 'static,
+        Self: Sized,
+        V: Predictor + Send + 'static,
+    {
+        let state = LabelState::new(threshold, padding_chunks);
+        Ok(OffloadLabelStream {
+            state,
+            stream: self.predict_offload(vad, max_in_flight)?,
+        })
+    }
 }
 
 impl<I: Stream> StreamExt for I {}
+
+/// Extensions for streams of fallible samples.
+///
+/// Unlike filtering the errors out, input errors are passed through as `Err`
+/// items without losing the audio buffered for the current chunk or padding.
+/// Import it with `use voice_activity_detector::TryStreamExt as _` to avoid
+/// clashing with the trait of the same name in `futures`.
+pub trait TryStreamExt<T, E>: Stream<Item = Result<T, E>> {
+    /// Creates a new [TryPredictStream] from a stream of fallible samples.
+    fn try_predict<V>(self, vad: V) -> TryPredictStream<T, Self, V>
+    where
+        T: Sample,
+        Self: Sized,
+        V: Predictor,
+    {
+        TryPredictStream {
+            stream: self,
+            state: PredictState::new(vad),
+        }
+    }
+
+    /// Creates a new [TryLabelStream] from a stream of fallible samples.
+    fn try_label<V>(
+        self,
+        vad: V,
+        threshold: f32,
+        padding_chunks: usize,
+    ) -> TryLabelStream<T, Self, V>
+    where
+        T: Sample,
+        Self: Sized,
+        V: Predictor,
+    {
+        let state = LabelState::new(threshold, padding_chunks);
+        TryLabelStream {
+            state,
+            stream: self.try_predict(vad),
+        }
+    }
+}
+
+impl<T, E, St: Stream<Item = Result<T, E>>> TryStreamExt<T, E> for St {}
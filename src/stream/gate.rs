@@ -0,0 +1,60 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use pin_project::pin_project;
+
+use crate::gate::{GateOptions, GateState};
+use crate::{LabeledAudio, Sample};
+
+/// Yields every sample of a stream of labeled audio, with non-speech scaled by
+/// a gain so the output has the same length as the input.
+#[pin_project]
+pub struct GateStream<T, St> {
+    #[pin]
+    stream: St,
+    chunk: std::vec::IntoIter<T>,
+    speech: bool,
+    state: GateState,
+}
+
+impl<T, St> GateStream<T, St> {
+    pub(super) fn new(stream: St, options: GateOptions) -> Self {
+        Self {
+            stream,
+            chunk: Vec::new().into_iter(),
+            speech: false,
+            state: GateState::new(options),
+        }
+    }
+
+    /// Consumes the gate, returning the labeled audio stream.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<T, St> Stream for GateStream<T, St>
+where
+    T: Sample,
+    St: Stream<Item = LabeledAudio<T>>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(sample) = this.chunk.next() {
+                return Poll::Ready(Some(this.state.apply(sample, *this.speech)));
+            }
+
+            let audio = match this.stream.as_mut().poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(audio)) => audio,
+            };
+            *this.speech = audio.is_speech();
+            *this.chunk = audio.into_iter();
+        }
+    }
+}
@@ -1,51 +1,144 @@
-use std::task::Poll;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
 use futures::Stream;
 use pin_project::pin_project;
 
-use crate::label::{LabelState, LabeledAudio};
-use crate::{PredictStream, Sample};
+use crate::label::{LabelControl, LabelState, LabeledAudio};
+use crate::{PredictStream, Predictor, Sample, TryPredictStream};
 
 /// Labels a stream of speech samples as either speech or non-speech according
 /// to the provided speech sensitity.
 #[pin_project]
-pub struct LabelStream<'a, T, St>
+pub struct LabelStream<T, St, V>
 where
     St: Stream,
 {
     #[pin]
-    pub(super) stream: PredictStream<'a, T, St>,
+    pub(super) stream: PredictStream<T, St, V>,
     pub(super) state: LabelState<T>,
 }
 
-impl<T, St> Stream for LabelStream<'_, T, St>
+impl<T, St, V> LabelStream<T, St, V>
 where
     T: Sample,
     St: Stream<Item = T>,
+    V: Predictor,
+{
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.state.control().clone()
+    }
+
+    /// Consumes the stream, returning the voice activity detector.
+    ///
+    /// Any audio still buffered for padding is discarded.
+    pub fn into_vad(self) -> V {
+        self.stream.into_vad()
+    }
+}
+
+impl<T, St, V> Stream for LabelStream<T, St, V>
+where
+    T: Sample,
+    St: Stream<Item = T>,
+    V: Predictor,
 {
     type Item = LabeledAudio<T>;
 
-    fn poll_next(
-        self: std::pin::Pin<&mut Self>,
-        cx: &mut std::task::Context<'_>,
-    ) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        poll_label(this.state, this.stream, cx)
+    }
+}
+
+/// Labels a stream of fallible speech samples as either speech or non-speech.
+///
+/// Errors are yielded as soon as they are read, which may be before chunks
+/// that were read earlier but are held back for padding. Labeling continues
+/// with the samples after an error if the consumer keeps polling.
+#[pin_project]
+pub struct TryLabelStream<T, St, V>
+where
+    St: Stream,
+{
+    #[pin]
+    pub(super) stream: TryPredictStream<T, St, V>,
+    pub(super) state: LabelState<T>,
+}
+
+impl<T, E, St, V> TryLabelStream<T, St, V>
+where
+    T: Sample,
+    St: Stream<Item = Result<T, E>>,
+    V: Predictor,
+{
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.state.control().clone()
+    }
+
+    /// Consumes the stream, returning the voice activity detector.
+    ///
+    /// Any audio still buffered for padding is discarded.
+    pub fn into_vad(self) -> V {
+        self.stream.into_vad()
+    }
+}
+
+impl<T, E, St, V> Stream for TryLabelStream<T, St, V>
+where
+    T: Sample,
+    St: Stream<Item = Result<T, E>>,
+    V: Predictor,
+{
+    type Item = Result<LabeledAudio<T>, E>;
 
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
         if let Some(buffered) = this.state.try_buffer() {
-            return Poll::Ready(Some(buffered));
+            return Poll::Ready(Some(Ok(buffered)));
         }
 
+        let mut stream = this.stream;
         loop {
-            let next = this.stream.as_mut().poll_next(cx);
-            let (chunk, probability) = match next {
+            let (chunk, probability) = match stream.as_mut().poll_next(cx) {
                 Poll::Pending => return Poll::Pending,
-                Poll::Ready(None) => return Poll::Ready(this.state.flush()),
-                Poll::Ready(Some(value)) => value,
+                Poll::Ready(None) => return Poll::Ready(this.state.flush().map(Ok)),
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(Some(Ok(value))) => value,
             };
 
             if let Some(audio) = this.state.try_next(chunk, probability) {
-                return Poll::Ready(Some(audio));
+                return Poll::Ready(Some(Ok(audio)));
             }
         }
     }
 }
+
+/// Polls a stream of predictions for the next labeled chunk of audio.
+pub(super) fn poll_label<T, P>(
+    state: &mut LabelState<T>,
+    mut stream: Pin<&mut P>,
+    cx: &mut Context<'_>,
+) -> Poll<Option<LabeledAudio<T>>>
+where
+    P: Stream<Item = (Vec<T>, f32)>,
+{
+    if let Some(buffered) = state.try_buffer() {
+        return Poll::Ready(Some(buffered));
+    }
+
+    loop {
+        let next = stream.as_mut().poll_next(cx);
+        let (chunk, probability) = match next {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => return Poll::Ready(state.flush()),
+            Poll::Ready(Some(value)) => value,
+        };
+
+        if let Some(audio) = state.try_next(chunk, probability) {
+            return Poll::Ready(Some(audio));
+        }
+    }
+}
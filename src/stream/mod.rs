@@ -1,7 +1,19 @@
+mod endpoint;
 mod extension;
+mod gate;
 mod label;
+#[cfg(feature = "async-tokio")]
+mod offload;
 mod predict;
+mod sink;
+mod stats;
 
+pub use endpoint::*;
 pub use extension::*;
+pub use gate::*;
 pub use label::*;
+#[cfg(feature = "async-tokio")]
+pub use offload::*;
 pub use predict::*;
+pub use sink::*;
+pub use stats::*;
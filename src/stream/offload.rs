@@ -0,0 +1,206 @@
+use std::task::{Context, Poll};
+use std::thread::JoinHandle;
+
+use futures::Stream;
+use pin_project::pin_project;
+use tokio::sync::mpsc;
+
+use crate::label::{LabelControl, LabelState, LabeledAudio};
+use crate::{Error, Predictor, Sample};
+
+/// Predicts speech in a stream of audio samples, running inference on a
+/// dedicated thread so the executor is never blocked by the model.
+///
+/// Chunks are predicted in order. At most `max_in_flight` chunks are queued
+/// for inference at once; the input stream is not polled while the queue is full.
+#[pin_project]
+pub struct OffloadPredictStream<T, St, V>
+where
+    St: Stream,
+{
+    #[pin]
+    pub(super) stream: St,
+    pub(super) buffer: Vec<T>,
+    pub(super) chunk_size: usize,
+    pub(super) max_in_flight: usize,
+    pub(super) in_flight: usize,
+    pub(super) done: bool,
+    pub(super) chunks: Option<mpsc::Sender<Vec<T>>>,
+    pub(super) predictions: mpsc::Receiver<(Vec<T>, f32)>,
+    pub(super) worker: Option<JoinHandle<V>>,
+    pub(super) vad: Option<V>,
+}
+
+impl<T, St, V> OffloadPredictStream<T, St, V>
+where
+    T: Sample + Send + 'static,
+    St: Stream<Item = T>,
+    V: Predictor + Send + 'static,
+{
+    pub(super) fn new(stream: St, vad: V, max_in_flight: usize) -> Result<Self, Error> {
+        let max_in_flight = max_in_flight.max(1);
+        let chunk_size = vad.chunk_size();
+        let (chunks, mut inputs) = mpsc::channel::<Vec<T>>(max_in_flight);
+        let (outputs, predictions) = mpsc::channel(max_in_flight);
+
+        let worker = std::thread::Builder::new()
+            .name("vad-inference".into())
+            .spawn(move || {
+                let mut vad = vad;
+                while let Some(chunk) = inputs.blocking_recv() {
+                    let probability = vad.predict_chunk(&chunk);
+                    if outputs.blocking_send((chunk, probability)).is_err() {
+                        break;
+                    }
+                }
+                vad
+            })?;
+
+        Ok(Self {
+            stream,
+            buffer: Vec::with_capacity(chunk_size),
+            chunk_size,
+            max_in_flight,
+            in_flight: 0,
+            done: false,
+            chunks: Some(chunks),
+            predictions,
+            worker: Some(worker),
+            vad: None,
+        })
+    }
+
+    /// Consumes the stream, returning the voice activity detector.
+    ///
+    /// This blocks until the inference thread has finished any chunk it is
+    /// currently predicting. Queued chunks and partially buffered samples are
+    /// discarded. If the stream has ended, the thread has already been joined.
+    pub fn into_vad(mut self) -> V {
+        self.chunks = None;
+        self.predictions.close();
+        join_worker(&mut self.worker, &mut self.vad);
+        self.vad
+            .expect("the detector is kept when the inference thread is joined")
+    }
+}
+
+impl<T, St, V> Stream for OffloadPredictStream<T, St, V>
+where
+    T: Sample + Send + 'static,
+    St: Stream<Item = T>,
+    V: Predictor + Send + 'static,
+{
+    type Item = (Vec<T>, f32);
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if *this.in_flight > 0 {
+                match this.predictions.poll_recv(cx) {
+                    Poll::Ready(Some(value)) => {
+                        *this.in_flight -= 1;
+                        return Poll::Ready(Some(value));
+                    }
+                    Poll::Ready(None) => {
+                        join_worker(this.worker, this.vad);
+                        return Poll::Ready(None);
+                    }
+                    Poll::Pending => {}
+                }
+            }
+
+            if *this.done || *this.in_flight >= *this.max_in_flight {
+                if *this.in_flight == 0 {
+                    // Every chunk has been returned, so the thread is idle and
+                    // exits as soon as its queue is closed.
+                    *this.chunks = None;
+                    join_worker(this.worker, this.vad);
+                    return Poll::Ready(None);
+                }
+                return Poll::Pending;
+            }
+
+            let sample = match this.stream.as_mut().poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    continue;
+                }
+                Poll::Ready(Some(sample)) => sample,
+            };
+
+            this.buffer.push(sample);
+            if this.buffer.len() < *this.chunk_size {
+                continue;
+            }
+
+            let chunk = std::mem::replace(this.buffer, Vec::with_capacity(*this.chunk_size));
+            let sender = this
+                .chunks
+                .as_ref()
+                .expect("the inference thread was already joined");
+            // The channel capacity matches the in flight limit, so this only
+            // fails if the worker has hung up.
+            if sender.try_send(chunk).is_err() {
+                join_worker(this.worker, this.vad);
+                return Poll::Ready(None);
+            }
+            *this.in_flight += 1;
+        }
+    }
+}
+
+/// Joins the inference thread if it is still running, keeping the detector it
+/// returns and propagating its panic.
+fn join_worker<V>(worker: &mut Option<JoinHandle<V>>, vad: &mut Option<V>) {
+    match worker.take().map(JoinHandle::join) {
+        Some(Ok(joined)) => *vad = Some(joined),
+        Some(Err(panic)) => std::panic::resume_unwind(panic),
+        None => {}
+    }
+}
+
+/// Labels a stream of speech samples as either speech or non-speech, running
+/// inference on a dedicated thread. See [OffloadPredictStream].
+#[pin_project]
+pub struct OffloadLabelStream<T, St, V>
+where
+    St: Stream,
+{
+    #[pin]
+    pub(super) stream: OffloadPredictStream<T, St, V>,
+    pub(super) state: LabelState<T>,
+}
+
+impl<T, St, V> OffloadLabelStream<T, St, V>
+where
+    T: Sample + Send + 'static,
+    St: Stream<Item = T>,
+    V: Predictor + Send + 'static,
+{
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.state.control().clone()
+    }
+
+    /// Consumes the stream, returning the voice activity detector.
+    ///
+    /// See [OffloadPredictStream::into_vad].
+    pub fn into_vad(self) -> V {
+        self.stream.into_vad()
+    }
+}
+
+impl<T, St, V> Stream for OffloadLabelStream<T, St, V>
+where
+    T: Sample + Send + 'static,
+    St: Stream<Item = T>,
+    V: Predictor + Send + 'static,
+{
+    type Item = LabeledAudio<T>;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        super::label::poll_label(this.state, this.stream, cx)
+    }
+}
@@ -4,23 +4,41 @@ use futures::Stream;
 use pin_project::pin_project;
 
 use crate::predict::PredictState;
-use crate::Sample;
+use crate::{Predictor, Sample};
 
 /// Predicts speech in a stream of audio samples.
+///
+/// The detector may be any [Predictor], such as an owned, boxed or borrowed
+/// detector or a [BatchedVadHandle](crate::BatchedVadHandle).
 #[pin_project]
-pub struct PredictStream<'a, T, St>
+pub struct PredictStream<T, St, V>
 where
     St: Stream,
 {
     #[pin]
     pub(super) stream: St,
-    pub(super) state: PredictState<'a, T>,
+    pub(super) state: PredictState<T, V>,
 }
 
-impl<T, St> Stream for PredictStream<'_, T, St>
+impl<T, St, V> PredictStream<T, St, V>
 where
     T: Sample,
     St: Stream<Item = T>,
+    V: Predictor,
+{
+    /// Consumes the stream, returning the voice activity detector.
+    ///
+    /// Any samples that have not yet filled a complete chunk are discarded.
+    pub fn into_vad(self) -> V {
+        self.state.into_vad()
+    }
+}
+
+impl<T, St, V> Stream for PredictStream<T, St, V>
+where
+    T: Sample,
+    St: Stream<Item = T>,
+    V: Predictor,
 {
     type Item = (Vec<T>, f32);
 
@@ -42,3 +60,60 @@ where
         }
     }
 }
+
+/// Predicts speech in a stream of fallible audio samples.
+///
+/// Errors are yielded as soon as they are read, and the samples after them
+/// continue the current chunk. The consumer decides whether to keep polling
+/// after an error.
+#[pin_project]
+pub struct TryPredictStream<T, St, V>
+where
+    St: Stream,
+{
+    #[pin]
+    pub(super) stream: St,
+    pub(super) state: PredictState<T, V>,
+}
+
+impl<T, E, St, V> TryPredictStream<T, St, V>
+where
+    T: Sample,
+    St: Stream<Item = Result<T, E>>,
+    V: Predictor,
+{
+    /// Consumes the stream, returning the voice activity detector.
+    ///
+    /// Any samples that have not yet filled a complete chunk are discarded.
+    pub fn into_vad(self) -> V {
+        self.state.into_vad()
+    }
+}
+
+impl<T, E, St, V> Stream for TryPredictStream<T, St, V>
+where
+    T: Sample,
+    St: Stream<Item = Result<T, E>>,
+    V: Predictor,
+{
+    type Item = Result<(Vec<T>, f32), E>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let sample = match this.stream.as_mut().poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(Some(Ok(next))) => next,
+            };
+            match this.state.try_next(sample) {
+                None => continue,
+                Some(value) => return Poll::Ready(Some(Ok(value))),
+            }
+        }
+    }
+}
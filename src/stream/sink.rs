@@ -0,0 +1,139 @@
+use std::borrow::BorrowMut;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::{Sink, Stream};
+use pin_project::pin_project;
+
+use crate::label::{LabelControl, LabeledAudio};
+use crate::{Error, Sample, VadProcessor, VoiceActivityDetector};
+
+/// A [Sink] of audio buffers that labels the audio as speech or non-speech.
+///
+/// The labeled audio is delivered to the paired [LabelReceiver]. The sink is not
+/// ready to accept another buffer until every chunk labeled from the previous buffer
+/// has been accepted by the receiver's channel. Closing the sink flushes the chunks
+/// buffered for padding and then ends the receiver's stream.
+#[pin_project]
+pub struct LabelSink<T, V = VoiceActivityDetector> {
+    processor: VadProcessor<T, V>,
+    pending: VecDeque<LabeledAudio<T>>,
+    finished: bool,
+    sender: mpsc::Sender<LabeledAudio<T>>,
+}
+
+/// The receiving half of a [LabelSink], yielding labeled chunks of audio.
+#[pin_project]
+pub struct LabelReceiver<T> {
+    #[pin]
+    receiver: mpsc::Receiver<LabeledAudio<T>>,
+}
+
+impl<T, V> LabelSink<T, V>
+where
+    T: Sample,
+    V: BorrowMut<VoiceActivityDetector>,
+{
+    /// Creates a new sink and its paired receiver.
+    ///
+    /// The channel between them holds up to `buffer` labeled chunks.
+    pub fn new(
+        vad: V,
+        threshold: f32,
+        padding_chunks: usize,
+        buffer: usize,
+    ) -> (Self, LabelReceiver<T>) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        let sink = Self {
+            processor: VadProcessor::new(vad, threshold, padding_chunks),
+            pending: VecDeque::new(),
+            finished: false,
+            sender,
+        };
+
+        (sink, LabelReceiver { receiver })
+    }
+
+    /// Returns a handle for changing the threshold and padding while labeling.
+    pub fn control(&self) -> LabelControl {
+        self.processor.control()
+    }
+
+    /// Consumes the sink, returning the voice activity detector.
+    pub fn into_vad(self) -> V {
+        self.processor.into_vad()
+    }
+}
+
+/// Sends pending chunks to the receiver until the channel is full.
+fn poll_pending<T>(
+    pending: &mut VecDeque<LabeledAudio<T>>,
+    sender: &mut mpsc::Sender<LabeledAudio<T>>,
+    cx: &mut Context<'_>,
+) -> Poll<Result<(), Error>> {
+    while !pending.is_empty() {
+        match sender.poll_ready(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(Err(_)) => return Poll::Ready(Err(Error::Disconnected)),
+            Poll::Ready(Ok(())) => {}
+        }
+        if let Some(audio) = pending.pop_front() {
+            sender.start_send(audio).map_err(|_| Error::Disconnected)?;
+        }
+    }
+
+    Poll::Ready(Ok(()))
+}
+
+impl<T, V> Sink<Vec<T>> for LabelSink<T, V>
+where
+    T: Sample,
+    V: BorrowMut<VoiceActivityDetector>,
+{
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        poll_pending(this.pending, this.sender, cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Vec<T>) -> Result<(), Self::Error> {
+        let this = self.project();
+        this.pending.extend(this.processor.push(&item));
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        if poll_pending(this.pending, this.sender, cx)?.is_pending() {
+            return Poll::Pending;
+        }
+        Pin::new(this.sender)
+            .poll_flush(cx)
+            .map_err(|_| Error::Disconnected)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        if !*this.finished {
+            this.pending.extend(this.processor.finish());
+            *this.finished = true;
+        }
+        if poll_pending(this.pending, this.sender, cx)?.is_pending() {
+            return Poll::Pending;
+        }
+        Pin::new(this.sender)
+            .poll_close(cx)
+            .map_err(|_| Error::Disconnected)
+    }
+}
+
+impl<T> Stream for LabelReceiver<T> {
+    type Item = LabeledAudio<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().receiver.poll_next(cx)
+    }
+}
@@ -0,0 +1,56 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use pin_project::pin_project;
+
+use crate::{LabeledAudio, SpeechStats};
+
+/// Passes labeled audio through unchanged, accumulating [SpeechStats].
+#[pin_project]
+pub struct StatsStream<St> {
+    #[pin]
+    stream: St,
+    stats: SpeechStats,
+}
+
+impl<St> StatsStream<St> {
+    pub(super) fn new(stream: St, stats: SpeechStats) -> Self {
+        Self { stream, stats }
+    }
+
+    /// Returns the statistics of the audio yielded so far.
+    pub fn stats(&self) -> &SpeechStats {
+        &self.stats
+    }
+
+    /// Consumes the stream, returning the statistics.
+    pub fn into_stats(self) -> SpeechStats {
+        self.stats
+    }
+
+    /// Consumes the stream, returning the labeled audio stream.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<T, St> Stream for StatsStream<St>
+where
+    St: Stream<Item = LabeledAudio<T>>,
+{
+    type Item = LabeledAudio<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let poll = this.stream.poll_next(cx);
+        if let Poll::Ready(Some(audio)) = &poll {
+            this.stats.push(audio);
+        }
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
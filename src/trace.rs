@@ -0,0 +1,267 @@
+use std::io::{self, BufRead, Read, Write};
+
+use crate::label::Labeler;
+use crate::{Error, IteratorExt, Sample, Segmenter, SpeechSegment, VoiceActivityDetector};
+
+/// The first bytes of a binary trace.
+const MAGIC: &[u8; 4] = b"VADT";
+/// The version of the binary trace format.
+const VERSION: u8 = 1;
+
+/// The speech probability of each chunk of a recording, which can be labeled
+/// again with different parameters without running the model.
+///
+/// Traces can be saved as CSV or a compact binary format, or as JSON with the
+/// `serde` feature. They contain no audio, so they can be shared when the
+/// recording cannot.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProbabilityTrace {
+    /// The sample rate of the audio.
+    pub sample_rate: i64,
+    /// The number of samples in each chunk.
+    pub chunk_size: usize,
+    /// The probability of speech for each chunk, in order.
+    pub probabilities: Vec<f32>,
+}
+
+impl ProbabilityTrace {
+    /// Creates an empty trace for audio of the given sample rate and chunk size.
+    pub fn new(sample_rate: i64, chunk_size: usize) -> Self {
+        Self {
+            sample_rate,
+            chunk_size,
+            probabilities: Vec::new(),
+        }
+    }
+
+    /// Records the probability of the next chunk.
+    pub fn push(&mut self, probability: f32) {
+        self.probabilities.push(probability);
+    }
+
+    /// Runs the detector over the samples, recording the probability of each chunk.
+    pub fn predict<S, I>(vad: &mut VoiceActivityDetector, samples: I) -> Self
+    where
+        S: Sample,
+        I: IntoIterator<Item = S>,
+    {
+        let probabilities = samples
+            .into_iter()
+            .predict(&mut *vad)
+            .map(|(_, probability)| probability)
+            .collect();
+
+        Self {
+            sample_rate: vad.sample_rate(),
+            chunk_size: vad.chunk_size(),
+            probabilities,
+        }
+    }
+
+    /// The duration of the recorded chunks, in seconds.
+    pub fn duration(&self) -> f64 {
+        self.seconds(self.probabilities.len())
+    }
+
+    fn seconds(&self, chunks: usize) -> f64 {
+        (chunks * self.chunk_size) as f64 / self.sample_rate as f64
+    }
+
+    /// Labels each chunk with the same padding semantics as
+    /// [LabelIterator](crate::LabelIterator), returning whether each chunk is
+    /// speech, in order.
+    pub fn label(&self, threshold: f32, padding_chunks: usize) -> Vec<bool> {
+        // Labels come out in chunk order, so the chunks need not carry any audio.
+        let mut labeler = Labeler::<()>::new(threshold, padding_chunks);
+        let mut labels = Vec::with_capacity(self.probabilities.len());
+        for &probability in &self.probabilities {
+            labels.extend(
+                labeler
+                    .push(Vec::new(), probability)
+                    .map(|audio| audio.is_speech()),
+            );
+        }
+        labels.extend(labeler.finish().map(|audio| audio.is_speech()));
+        labels
+    }
+
+    /// Returns the speech segments found with the given parameters. Segments
+    /// shorter than `min_duration` seconds are discarded.
+    pub fn segments(
+        &self,
+        threshold: f32,
+        padding_chunks: usize,
+        min_duration: f64,
+    ) -> Vec<SpeechSegment> {
+        self.segments_from_labels(&self.label(threshold, padding_chunks), min_duration)
+    }
+
+    /// Groups chunk labels from [ProbabilityTrace::label] into speech segments.
+    pub(crate) fn segments_from_labels(
+        &self,
+        labels: &[bool],
+        min_duration: f64,
+    ) -> Vec<SpeechSegment> {
+        let mut segmenter = Segmenter::new(self.sample_rate as u32).with_min_duration(min_duration);
+        let mut segments: Vec<SpeechSegment> = labels
+            .iter()
+            .filter_map(|&speech| segmenter.push_label(speech, self.chunk_size))
+            .collect();
+        segments.extend(segmenter.finish());
+        segments
+    }
+
+    /// Writes the trace as CSV, with the sample rate and chunk size in comment
+    /// lines followed by a `chunk_index,probability` row for each chunk.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "# sample_rate={}", self.sample_rate)?;
+        writeln!(writer, "# chunk_size={}", self.chunk_size)?;
+        writeln!(writer, "chunk_index,probability")?;
+        for (index, probability) in self.probabilities.iter().enumerate() {
+            writeln!(writer, "{index},{probability}")?;
+        }
+        Ok(())
+    }
+
+    /// Reads a trace written by [ProbabilityTrace::write_csv].
+    ///
+    /// Returns [Error::TraceFormatError] if the sample rate or chunk size
+    /// comment is missing or invalid.
+    pub fn read_csv<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let (mut sample_rate, mut chunk_size) = (None, None);
+        let mut probabilities = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            let error = |message: String| Error::ParseError {
+                line: i + 1,
+                message,
+            };
+
+            if let Some(comment) = line.strip_prefix('#') {
+                let Some((key, value)) = comment.trim().split_once('=') else {
+                    continue;
+                };
+                let value = value.trim();
+                match key.trim() {
+                    "sample_rate" => {
+                        sample_rate = Some(
+                            value
+                                .parse()
+                                .map_err(|_| error(format!("`{value}` is not a sample rate")))?,
+                        )
+                    }
+                    "chunk_size" => {
+                        chunk_size = Some(
+                            value
+                                .parse()
+                                .map_err(|_| error(format!("`{value}` is not a chunk size")))?,
+                        )
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            if line.is_empty() || line == "chunk_index,probability" {
+                continue;
+            }
+
+            let (index, probability) = line
+                .split_once(',')
+                .ok_or_else(|| error("expected `chunk_index,probability`".into()))?;
+            if index.trim().parse::<usize>().ok() != Some(probabilities.len()) {
+                return Err(error(format!(
+                    "expected chunk index {}, found `{index}`",
+                    probabilities.len()
+                )));
+            }
+            let probability = probability
+                .trim()
+                .parse()
+                .map_err(|_| error(format!("`{probability}` is not a probability")))?;
+            probabilities.push(probability);
+        }
+
+        let missing = |name: &str| Error::TraceFormatError {
+            message: format!("missing the `# {name}=` comment"),
+        };
+        Self {
+            sample_rate: sample_rate.ok_or_else(|| missing("sample_rate"))?,
+            chunk_size: chunk_size.ok_or_else(|| missing("chunk_size"))?,
+            probabilities,
+        }
+        .validate()
+    }
+
+    /// Writes the trace in a compact little endian binary format: a header with
+    /// the sample rate, chunk size and chunk count, followed by each probability
+    /// as an `f32`.
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&self.sample_rate.to_le_bytes())?;
+        writer.write_all(&(self.chunk_size as u64).to_le_bytes())?;
+        writer.write_all(&(self.probabilities.len() as u64).to_le_bytes())?;
+        for probability in &self.probabilities {
+            writer.write_all(&probability.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Reads a trace written by [ProbabilityTrace::write_binary].
+    ///
+    /// Returns [Error::TraceFormatError] if the header is not a trace header, or
+    /// has an invalid sample rate or chunk size.
+    pub fn read_binary<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let invalid = |message: &str| Error::TraceFormatError {
+            message: message.to_string(),
+        };
+
+        let mut header = [0u8; 5];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(invalid("not a probability trace"));
+        }
+        if header[4] != VERSION {
+            return Err(invalid("unsupported version"));
+        }
+
+        let mut word = [0u8; 8];
+        reader.read_exact(&mut word)?;
+        let sample_rate = i64::from_le_bytes(word);
+        reader.read_exact(&mut word)?;
+        let chunk_size = usize::try_from(u64::from_le_bytes(word))
+            .map_err(|_| invalid("the chunk size is too large"))?;
+        reader.read_exact(&mut word)?;
+        let count = u64::from_le_bytes(word);
+
+        let mut probabilities = Vec::new();
+        let mut bytes = [0u8; 4];
+        for _ in 0..count {
+            reader.read_exact(&mut bytes)?;
+            probabilities.push(f32::from_le_bytes(bytes));
+        }
+
+        Self {
+            sample_rate,
+            chunk_size,
+            probabilities,
+        }
+        .validate()
+    }
+
+    /// Checks that a loaded trace has a usable sample rate and chunk size, so
+    /// its duration and segments can be computed.
+    fn validate(self) -> Result<Self, Error> {
+        let message = if self.sample_rate <= 0 || self.sample_rate > i64::from(u32::MAX) {
+            format!("the sample rate {} is out of range", self.sample_rate)
+        } else if self.chunk_size == 0 {
+            "the chunk size must be positive".to_string()
+        } else {
+            return Ok(self);
+        };
+        Err(Error::TraceFormatError { message })
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{evaluate, Error, EvalOptions, EvalReport, ProbabilityTrace, SpeechSegment};
+
+/// The labeling parameters to search.
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct TuneGrid {
+    /// The thresholds to try. Defaults to 0.05 to 0.95 in steps of 0.05.
+    #[builder(default = (1..20).map(|step| step as f32 * 0.05).collect())]
+    pub thresholds: Vec<f32>,
+    /// The padding chunks to try.
+    #[builder(default = vec![0, 1, 2, 3, 5, 8])]
+    pub padding_chunks: Vec<usize>,
+    /// The minimum speech durations to try, in seconds.
+    #[builder(default = vec![0.0])]
+    pub min_durations: Vec<f64>,
+}
+
+impl Default for TuneGrid {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The score used to choose the best configuration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TuneObjective {
+    /// Maximize the frame-level F1 score.
+    #[default]
+    F1,
+    /// Minimize the detection error rate.
+    DetectionErrorRate,
+}
+
+impl TuneObjective {
+    fn score(self, report: &EvalReport) -> f64 {
+        match self {
+            TuneObjective::F1 => report.f1,
+            TuneObjective::DetectionErrorRate => -report.detection_error_rate,
+        }
+    }
+}
+
+/// A single configuration from the grid, scored across every file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TunePoint {
+    /// The threshold used for labeling.
+    pub threshold: f32,
+    /// The padding chunks used for labeling.
+    pub padding_chunks: usize,
+    /// The minimum speech duration, in seconds.
+    pub min_duration: f64,
+    /// The evaluation of every file, as if they were one recording.
+    pub report: EvalReport,
+}
+
+/// A point on the ROC and DET curves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CurvePoint {
+    /// The threshold used for labeling.
+    pub threshold: f32,
+    /// The fraction of non-speech labeled as speech, the x axis of both curves.
+    pub false_alarm_rate: f64,
+    /// The fraction of speech labeled as speech, the y axis of the ROC curve.
+    pub true_positive_rate: f64,
+    /// The fraction of speech labeled as non-speech, the y axis of the DET curve.
+    pub miss_rate: f64,
+}
+
+/// The results of a parameter sweep.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TuneReport {
+    /// The best configuration according to the objective.
+    pub best: TunePoint,
+    /// Every configuration in the grid.
+    pub points: Vec<TunePoint>,
+    /// The ROC and DET curves across thresholds, using the best padding and
+    /// minimum duration, ordered by threshold.
+    pub curve: Vec<CurvePoint>,
+}
+
+/// Scores one configuration across every file, treating the files as one
+/// recording so that their frames are weighted equally. `labels` holds the
+/// chunk labels of each file for the threshold and padding.
+fn score(
+    files: &[(ProbabilityTrace, Vec<SpeechSegment>)],
+    labels: &[Vec<bool>],
+    threshold: f32,
+    padding_chunks: usize,
+    min_duration: f64,
+    options: &EvalOptions,
+) -> Result<TunePoint, Error> {
+    let (mut reference, mut hypothesis) = (Vec::new(), Vec::new());
+    let mut offset = 0.0;
+    let shift = |segment: &SpeechSegment, offset: f64| {
+        SpeechSegment::new(segment.start + offset, segment.end + offset)
+    };
+    for ((trace, truth), labels) in files.iter().zip(labels) {
+        let duration = trace.duration();
+        reference.extend(truth.iter().map(|segment| shift(segment, offset)));
+        hypothesis.extend(
+            trace
+                .segments_from_labels(labels, min_duration)
+                .iter()
+                .map(|segment| shift(segment, offset)),
+        );
+        offset += duration;
+    }
+
+    let options = EvalOptions {
+        duration: Some(offset),
+        ..options.clone()
+    };
+    Ok(TunePoint {
+        threshold,
+        padding_chunks,
+        min_duration,
+        report: evaluate(&reference, &hypothesis, &options)?,
+    })
+}
+
+/// Searches the grid of labeling parameters for the configuration that best
+/// matches the ground truth.
+///
+/// Each file is given as a [ProbabilityTrace], so the model is run once per file
+/// rather than once per grid point, paired with its reference speech segments.
+/// Returns `None` if the grid is empty, or [Error::EvalConfigError] if the
+/// frame length is not positive.
+pub fn tune(
+    files: &[(ProbabilityTrace, Vec<SpeechSegment>)],
+    grid: &TuneGrid,
+    objective: TuneObjective,
+    options: &EvalOptions,
+) -> Result<Option<TuneReport>, Error> {
+    let mut points = Vec::new();
+    for &threshold in &grid.thresholds {
+        for &padding_chunks in &grid.padding_chunks {
+            // The minimum duration only filters segments, so each file is
+            // labeled once per threshold and padding.
+            let labels: Vec<Vec<bool>> = files
+                .iter()
+                .map(|(trace, _)| trace.label(threshold, padding_chunks))
+                .collect();
+            for &min_duration in &grid.min_durations {
+                points.push(score(
+                    files,
+                    &labels,
+                    threshold,
+                    padding_chunks,
+                    min_duration,
+                    options,
+                )?);
+            }
+        }
+    }
+
+    let Some(&best) = points.iter().max_by(|a, b| {
+        objective
+            .score(&a.report)
+            .total_cmp(&objective.score(&b.report))
+    }) else {
+        return Ok(None);
+    };
+
+    let mut curve: Vec<CurvePoint> = points
+        .iter()
+        .filter(|point| {
+            point.padding_chunks == best.padding_chunks && point.min_duration == best.min_duration
+        })
+        .map(|point| CurvePoint {
+            threshold: point.threshold,
+            false_alarm_rate: point.report.false_alarm_rate,
+            true_positive_rate: point.report.recall,
+            miss_rate: point.report.miss_rate,
+        })
+        .collect();
+    curve.sort_by(|a, b| a.threshold.total_cmp(&b.threshold));
+
+    Ok(Some(TuneReport {
+        best,
+        points,
+        curve,
+    }))
+}
@@ -1,22 +1,25 @@
 use ort::{session::builder::GraphOptimizationLevel, session::Session};
 use std::sync::{Arc, LazyLock};
 
-use crate::{error::Error, Sample};
+use crate::{error::Error, Preprocessor, Sample};
 
 /// A voice activity detector session.
-#[derive(Debug)]
+///
+/// Cloning a detector shares its ONNX session and copies its current state.
+#[derive(Clone, Debug)]
 pub struct VoiceActivityDetector {
     session: Arc<Session>,
     chunk_size: usize,
     sample_rate: i64,
     h: ndarray::Array3<f32>,
     c: ndarray::Array3<f32>,
+    preprocessor: Option<Preprocessor>,
 }
 
 /// The silero ONNX model as bytes.
 const MODEL: &[u8] = include_bytes!("silero_vad.onnx");
 
-static DEFAULT_SESSION: LazyLock<Arc<Session>> = LazyLock::new(|| {
+pub(crate) static DEFAULT_SESSION: LazyLock<Arc<Session>> = LazyLock::new(|| {
     Arc::new({
         Session::builder()
             .unwrap()
@@ -38,14 +41,55 @@ impl VoiceActivityDetector {
     }
 
     /// Gets the chunks size
-    pub(crate) fn chunk_size(&self) -> usize {
+    pub fn chunk_size(&self) -> usize {
         self.chunk_size
     }
 
+    /// Gets the sample rate
+    pub fn sample_rate(&self) -> i64 {
+        self.sample_rate
+    }
+
     /// Resets the state of the voice activity detector session.
     pub fn reset(&mut self) {
         self.h.fill(0f32);
         self.c.fill(0f32);
+        if let Some(preprocessor) = self.preprocessor.as_mut() {
+            preprocessor.reset();
+        }
+    }
+
+    /// Returns the flattened `h` and `c` states of the model.
+    pub(crate) fn lstm_state(&self) -> (Vec<f32>, Vec<f32>) {
+        (
+            self.h.iter().copied().collect(),
+            self.c.iter().copied().collect(),
+        )
+    }
+
+    /// Restores states returned by [VoiceActivityDetector::lstm_state]. States
+    /// of the wrong size leave the detector unchanged.
+    pub(crate) fn set_lstm_state(&mut self, h: &[f32], c: &[f32]) -> Result<(), Error> {
+        if h.len() != self.h.len() || c.len() != self.c.len() {
+            return Err(Error::SnapshotError {
+                message: format!(
+                    "the LSTM states have {} and {} values instead of {} and {}",
+                    h.len(),
+                    c.len(),
+                    self.h.len(),
+                    self.c.len()
+                ),
+            });
+        }
+        self.h
+            .iter_mut()
+            .zip(h)
+            .for_each(|(state, &value)| *state = value);
+        self.c
+            .iter_mut()
+            .zip(c)
+            .for_each(|(state, &value)| *state = value);
+        Ok(())
     }
 
     /// Predicts the existence of speech in a single iterable of audio.
@@ -61,6 +105,10 @@ impl VoiceActivityDetector {
         for (i, sample) in samples.into_iter().take(self.chunk_size).enumerate() {
             input[[0, i]] = sample.to_f32();
         }
+        if let Some(preprocessor) = self.preprocessor.as_mut() {
+            let input = input.as_slice_mut().expect("the input is contiguous");
+            preprocessor.process(input, self.sample_rate);
+        }
 
         let sample_rate = ndarray::arr1::<i64>(&[self.sample_rate]);
 
@@ -101,6 +149,55 @@ impl VoiceActivityDetector {
     }
 }
 
+/// Checks that the model supports the sample rate and chunk size.
+pub(crate) fn check_config(sample_rate: i64, chunk_size: usize) -> Result<(), Error> {
+    if (sample_rate as f32) / (chunk_size as f32) > 31.25 {
+        return Err(Error::VadConfigError {
+            sample_rate,
+            chunk_size,
+        });
+    }
+    Ok(())
+}
+
+/// A validated sample rate and chunk size with the session to run them on,
+/// shared by services that create detectors as they go.
+#[derive(Clone, Debug)]
+pub(crate) struct DetectorConfig {
+    pub session: Arc<Session>,
+    pub sample_rate: i64,
+    pub chunk_size: usize,
+}
+
+impl DetectorConfig {
+    /// Checks the sample rate and chunk size, using the bundled model if no
+    /// session is given.
+    pub fn new(
+        sample_rate: i64,
+        chunk_size: usize,
+        session: Option<Arc<Session>>,
+    ) -> Result<Self, Error> {
+        check_config(sample_rate, chunk_size)?;
+        Ok(Self {
+            session: session.unwrap_or_else(|| DEFAULT_SESSION.clone()),
+            sample_rate,
+            chunk_size,
+        })
+    }
+
+    /// Creates a detector with a reset state.
+    pub fn detector(&self) -> VoiceActivityDetector {
+        VoiceActivityDetector {
+            session: self.session.clone(),
+            chunk_size: self.chunk_size,
+            sample_rate: self.sample_rate,
+            h: ndarray::Array3::<f32>::zeros((2, 1, 64)),
+            c: ndarray::Array3::<f32>::zeros((2, 1, 64)),
+            preprocessor: None,
+        }
+    }
+}
+
 /// The configuration for the [VoiceActivityDetector]. Used to create
 /// a [VoiceActivityDetectorBuilder] that performs runtime validation on build.
 #[derive(Debug, typed_builder::TypedBuilder)]
@@ -116,25 +213,17 @@ struct VoiceActivityDetectorConfig {
     sample_rate: i64,
     #[builder(default, setter(strip_option))]
     session: Option<Arc<Session>>,
+    #[builder(default, setter(strip_option))]
+    preprocessor: Option<Preprocessor>,
 }
 
 impl From<VoiceActivityDetectorConfig> for Result<VoiceActivityDetector, Error> {
     fn from(value: VoiceActivityDetectorConfig) -> Self {
-        if (value.sample_rate as f32) / (value.chunk_size as f32) > 31.25 {
-            return Err(Error::VadConfigError {
-                sample_rate: value.sample_rate,
-                chunk_size: value.chunk_size,
-            });
-        }
-
-        let session = value.session.unwrap_or_else(|| DEFAULT_SESSION.clone());
+        let config = DetectorConfig::new(value.sample_rate, value.chunk_size, value.session)?;
 
         Ok(VoiceActivityDetector {
-            session,
-            chunk_size: value.chunk_size,
-            sample_rate: value.sample_rate,
-            h: ndarray::Array3::<f32>::zeros((2, 1, 64)),
-            c: ndarray::Array3::<f32>::zeros((2, 1, 64)),
+            preprocessor: value.preprocessor,
+            ..config.detector()
         })
     }
 }
@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+
+use crate::{Error, LabeledAudio, Segmenter, SpeechSegment, VadProcessor, VoiceActivityDetector};
+
+/// Options for labeling a WAV file.
+#[derive(Clone, Debug, typed_builder::TypedBuilder)]
+pub struct WavOptions {
+    /// Probabilities greater than or equal to this value are considered speech.
+    #[builder(default = 0.5)]
+    pub threshold: f32,
+    /// The number of chunks to add to the start and end of speech.
+    #[builder(default = 3)]
+    pub padding_chunks: usize,
+    /// The chunk size for the detector. Defaults to
+    /// [WavOptions::default_chunk_size] for the file's sample rate.
+    #[builder(default, setter(strip_option))]
+    pub chunk_size: Option<usize>,
+    /// A directory to write each speech segment to, as its own WAV file.
+    #[builder(default, setter(strip_option, into))]
+    pub segment_dir: Option<PathBuf>,
+}
+
+impl WavOptions {
+    /// Returns the crate's default chunk size for the sample rate: the smallest
+    /// power of two, no less than 256, that the model accepts at that rate.
+    /// This is 256 samples at 8 kHz and 512 at 16 kHz.
+    pub fn default_chunk_size(sample_rate: u32) -> usize {
+        let minimum = (f64::from(sample_rate) / 31.25).ceil() as usize;
+        minimum.max(256).next_power_of_two()
+    }
+
+    /// Returns the chunk size to use for the sample rate.
+    pub fn chunk_size_for(&self, sample_rate: u32) -> usize {
+        self.chunk_size
+            .unwrap_or_else(|| Self::default_chunk_size(sample_rate))
+    }
+
+    /// Creates a new detector configured for a WAV file.
+    pub fn vad(&self, spec: WavSpec) -> Result<VoiceActivityDetector, Error> {
+        VoiceActivityDetector::builder()
+            .sample_rate(spec.sample_rate)
+            .chunk_size(self.chunk_size_for(spec.sample_rate))
+            .build()
+    }
+}
+
+impl Default for WavOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Labels the audio of a WAV file as speech or non-speech.
+///
+/// Samples of any bit depth are converted to `f32`, and multi-channel audio is
+/// mixed down to mono before labeling. Read errors are returned as `Err` items,
+/// after which the iterator ends.
+pub struct WavLabels<R> {
+    reader: WavReader<R>,
+    processor: VadProcessor<f32>,
+    pending: VecDeque<LabeledAudio<f32>>,
+    frame: Vec<f32>,
+    chunk: Vec<f32>,
+    done: bool,
+}
+
+impl<R: Read> WavLabels<R> {
+    /// Creates a new labeler, configuring a detector from the WAV spec.
+    pub fn new(reader: WavReader<R>, options: &WavOptions) -> Result<Self, Error> {
+        let vad = options.vad(reader.spec())?;
+        Ok(Self::with_vad(reader, vad, options))
+    }
+
+    /// Creates a new labeler using an existing detector, which should be
+    /// configured for the WAV file's sample rate.
+    pub fn with_vad(
+        reader: WavReader<R>,
+        vad: VoiceActivityDetector,
+        options: &WavOptions,
+    ) -> Self {
+        let channels = usize::from(reader.spec().channels);
+        let chunk_size = vad.chunk_size();
+        Self {
+            reader,
+            processor: VadProcessor::new(vad, options.threshold, options.padding_chunks),
+            pending: VecDeque::new(),
+            frame: Vec::with_capacity(channels),
+            chunk: Vec::with_capacity(chunk_size),
+            done: false,
+        }
+    }
+
+    /// Consumes the labeler, returning the voice activity detector.
+    pub fn into_vad(self) -> VoiceActivityDetector {
+        self.processor.into_vad()
+    }
+
+    /// Returns the spec of the WAV file.
+    pub fn spec(&self) -> WavSpec {
+        self.reader.spec()
+    }
+
+    /// Reads up to one chunk of mono samples, returning false at the end of the file.
+    fn read_chunk(&mut self, chunk_size: usize) -> Result<bool, Error> {
+        let spec = self.reader.spec();
+        let channels = usize::from(spec.channels);
+        self.chunk.clear();
+
+        while self.chunk.len() < chunk_size {
+            self.frame.clear();
+            match spec.sample_format {
+                SampleFormat::Float => {
+                    for sample in self.reader.samples::<f32>().take(channels) {
+                        self.frame.push(sample?);
+                    }
+                }
+                SampleFormat::Int => {
+                    let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+                    for sample in self.reader.samples::<i32>().take(channels) {
+                        self.frame.push(sample? as f32 / scale);
+                    }
+                }
+            }
+
+            if self.frame.len() < channels {
+                return Ok(!self.chunk.is_empty());
+            }
+            self.chunk
+                .push(self.frame.iter().sum::<f32>() / channels as f32);
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for WavLabels<R> {
+    type Item = Result<LabeledAudio<f32>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(audio) = self.pending.pop_front() {
+                return Some(Ok(audio));
+            }
+            if self.done {
+                return None;
+            }
+
+            let chunk_size = self.processor.vad_mut().chunk_size();
+            match self.read_chunk(chunk_size) {
+                Ok(true) => self.pending.extend(self.processor.push(&self.chunk)),
+                Ok(false) => {
+                    self.done = true;
+                    self.pending.extend(self.processor.finish());
+                }
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+impl VoiceActivityDetector {
+    /// Opens a WAV file and labels its audio as speech or non-speech.
+    ///
+    /// The detector is configured from the file's spec. See [WavLabels].
+    pub fn label_wav(
+        path: impl AsRef<Path>,
+        options: &WavOptions,
+    ) -> Result<WavLabels<BufReader<File>>, Error> {
+        WavLabels::new(WavReader::open(path)?, options)
+    }
+
+    /// Finds the speech segments in a WAV file.
+    ///
+    /// If [WavOptions::segment_dir] is set, each segment is also written to its own
+    /// mono WAV file in that directory, named after the input file and the segment index.
+    pub fn segments_from_wav(
+        path: impl AsRef<Path>,
+        options: &WavOptions,
+    ) -> Result<Vec<SpeechSegment>, Error> {
+        let path = path.as_ref();
+        let labels = Self::label_wav(path, options)?;
+        let spec = labels.spec();
+        let mut segmenter = Segmenter::new(spec.sample_rate);
+        let mut segments = Vec::new();
+
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let spec = WavSpec {
+            channels: 1,
+            ..spec
+        };
+        let mut writer = None;
+
+        for audio in labels {
+            let audio = audio?;
+            if let Some(segment) = segmenter.push(&audio) {
+                segments.push(segment);
+            }
+
+            let Some(dir) = &options.segment_dir else {
+                continue;
+            };
+            if !audio.is_speech() {
+                if let Some(writer) = writer.take() {
+                    WavWriter::finalize(writer)?;
+                }
+                continue;
+            }
+            if writer.is_none() {
+                let file = dir.join(format!("{stem}.{}.wav", segments.len()));
+                writer = Some(WavWriter::create(file, spec)?);
+            }
+            if let Some(writer) = writer.as_mut() {
+                write_wav_samples(writer, audio.as_slice())?;
+            }
+        }
+
+        segments.extend(segmenter.finish());
+        if let Some(writer) = writer {
+            writer.finalize()?;
+        }
+
+        Ok(segments)
+    }
+}
+
+/// Writes `f32` samples to a WAV file, converting them to the writer's sample
+/// format and bit depth.
+pub fn write_wav_samples<W>(writer: &mut WavWriter<W>, samples: &[f32]) -> Result<(), Error>
+where
+    W: std::io::Write + std::io::Seek,
+{
+    let spec = writer.spec();
+    let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+    for &sample in samples {
+        match spec.sample_format {
+            SampleFormat::Float => writer.write_sample(sample)?,
+            SampleFormat::Int => {
+                let sample = (sample * scale).round().clamp(-scale, scale - 1.0);
+                writer.write_sample(sample as i32)?
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "batch")]
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use voice_activity_detector::{process_directory, BatchOptions, ManifestEntry};
+
+fn read_manifest(path: &Path) -> Result<Vec<ManifestEntry>, Box<dyn std::error::Error>> {
+    let mut entries = Vec::new();
+    for line in fs::read_to_string(path)?.lines() {
+        entries.push(serde_json::from_str(line)?);
+    }
+    Ok(entries)
+}
+
+/// Creates an empty directory for a test's input files.
+fn input_dir(name: &str) -> Result<PathBuf, std::io::Error> {
+    let dir = std::env::temp_dir().join(format!("vad-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+#[test]
+fn batch_directory() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = &input_dir("batch")?;
+    fs::create_dir_all(dir.join("nested"))?;
+    fs::copy("tests/samples/sample.wav", dir.join("a.wav"))?;
+    fs::copy("tests/samples/sample.wav", dir.join("nested/b.WAV"))?;
+    fs::write(dir.join("corrupt.wav"), b"not a wav file")?;
+    fs::write(dir.join("notes.txt"), b"ignored")?;
+
+    let manifest = dir.join("manifest.jsonl");
+    let options = BatchOptions::builder()
+        .manifest(&manifest)
+        .workers(2)
+        .build();
+    let summary = process_directory(dir, &options)?;
+    assert_eq!(
+        (2, 1, 0),
+        (summary.processed, summary.failed, summary.skipped)
+    );
+
+    let entries = read_manifest(&manifest)?;
+    assert_eq!(3, entries.len());
+    for entry in &entries {
+        assert!(entry.path.is_relative());
+        if entry.path.ends_with("corrupt.wav") {
+            assert!(entry.error.is_some());
+        } else {
+            assert!(entry.error.is_none());
+            assert!(!entry.segments.is_empty());
+            assert!(entry.speech_ratio > 0.0 && entry.speech_ratio <= 1.0);
+        }
+    }
+
+    // Running again skips the files that succeeded and retries the one that
+    // failed, even when the directory is spelled differently.
+    let summary = process_directory(dir.join("."), &options)?;
+    assert_eq!(
+        (0, 1, 2),
+        (summary.processed, summary.failed, summary.skipped)
+    );
+    assert_eq!(4, read_manifest(&manifest)?.len());
+
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn batch_resumes_from_manifest() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = &input_dir("batch-resume")?;
+    fs::write(dir.join("done.wav"), b"already processed")?;
+    fs::write(dir.join("corrupt.wav"), b"not a wav file")?;
+
+    let manifest = dir.join("manifest.jsonl");
+    let done = ManifestEntry {
+        path: PathBuf::from("done.wav"),
+        duration: 1.0,
+        speech_ratio: 0.0,
+        segments: Vec::new(),
+        error: None,
+    };
+    let mut file = fs::File::create(&manifest)?;
+    writeln!(file, "{}", serde_json::to_string(&done)?)?;
+    // A line cut short by an interrupted batch is ignored.
+    write!(file, "{{\"path\":")?;
+    drop(file);
+
+    let options = BatchOptions::builder().manifest(&manifest).build();
+    let summary = process_directory(dir, &options)?;
+    assert_eq!(
+        (0, 1, 1),
+        (summary.processed, summary.failed, summary.skipped)
+    );
+
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
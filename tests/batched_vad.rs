@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use voice_activity_detector::{BatchedVad, Error, IteratorExt, VoiceActivityDetector};
+
+#[test]
+fn batched_vad_config_error() {
+    let result = BatchedVad::builder()
+        .sample_rate(16000)
+        .chunk_size(256usize)
+        .build();
+    assert!(matches!(result, Err(Error::VadConfigError { .. })));
+}
+
+#[test]
+fn batched_matches_sequential() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let samples: Vec<i16> = reader.samples::<i16>().collect::<Result<_, _>>()?;
+    let chunks: Vec<Vec<i16>> = samples.chunks_exact(256).map(<[i16]>::to_vec).collect();
+
+    let mut vad = VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()?;
+    let expected: Vec<f32> = chunks
+        .iter()
+        .map(|chunk| vad.predict(chunk.iter().copied()))
+        .collect();
+
+    let service = BatchedVad::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .max_batch_size(8usize)
+        .max_wait(Duration::from_millis(5))
+        .build()?;
+
+    // Streams offset from each other so each batch mixes different states.
+    let streams: Vec<_> = (0..8)
+        .map(|offset| {
+            let mut handle = service.handle();
+            let chunks = chunks.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(offset));
+                chunks
+                    .iter()
+                    .map(|chunk| handle.predict(chunk.iter().copied()))
+                    .collect::<Result<Vec<f32>, Error>>()
+            })
+        })
+        .collect();
+    drop(service);
+
+    for stream in streams {
+        let probabilities = stream.join().unwrap()?;
+        assert_eq!(expected.len(), probabilities.len());
+        for (expected, actual) in expected.iter().zip(probabilities) {
+            assert!((expected - actual).abs() < 1e-4);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn batched_predict_async() -> Result<(), Box<dyn std::error::Error>> {
+    let service = BatchedVad::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()?;
+
+    let mut first = service.handle();
+    let mut second = service.handle();
+    let (a, b) = tokio::join!(
+        first.predict_async([0i16; 256]),
+        second.predict_async([0i16; 256])
+    );
+    let (a, b) = (a?, b?);
+    assert_eq!(a, b);
+
+    first.reset();
+    assert_eq!(a, first.predict_async([0i16; 256]).await?);
+    Ok(())
+}
+
+#[test]
+fn batched_handle_drives_label_iterator() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let samples: Vec<i16> = reader.samples::<i16>().collect::<Result<_, _>>()?;
+
+    let mut vad = VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()?;
+    let expected: Vec<bool> = samples
+        .iter()
+        .copied()
+        .label(&mut vad, 0.5, 3)
+        .map(|label| label.is_speech())
+        .collect();
+
+    let service = BatchedVad::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()?;
+    let labels: Vec<bool> = samples
+        .into_iter()
+        .label(service.handle(), 0.5, 3)
+        .map(|label| label.is_speech())
+        .collect();
+
+    assert_eq!(expected, labels);
+    Ok(())
+}
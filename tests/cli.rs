@@ -0,0 +1,135 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const VAD: &str = env!("CARGO_BIN_EXE_vad");
+
+#[test]
+fn cli_text_output() -> Result<(), Box<dyn std::error::Error>> {
+    let output = Command::new(VAD)
+        .args([
+            "tests/samples/sample.wav",
+            "--threshold",
+            "0.5",
+            "--padding-chunks",
+            "10",
+        ])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(!stdout.is_empty());
+    for line in stdout.lines() {
+        let (start, end) = line
+            .split_once('\t')
+            .ok_or("expected a tab separated line")?;
+        assert!(start.parse::<f64>()? < end.parse::<f64>()?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn cli_json_output_with_segments() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = "tests/.outputs/cli_segments";
+    std::fs::create_dir_all(dir)?;
+
+    let output = Command::new(VAD)
+        .args([
+            "tests/samples/sample.wav",
+            "--format",
+            "json",
+            "--segment-dir",
+            dir,
+        ])
+        .args(["--speech-output", "tests/.outputs/cli.speech.wav"])
+        .output()?;
+    assert!(output.status.success());
+
+    let segments: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)?;
+    assert!(!segments.is_empty());
+    for i in 0..segments.len() {
+        hound::WavReader::open(format!("{dir}/sample.{i}.wav"))?;
+    }
+    hound::WavReader::open("tests/.outputs/cli.speech.wav")?;
+
+    Ok(())
+}
+
+#[test]
+fn cli_raw_stdin() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let sample_rate = reader.spec().sample_rate.to_string();
+    let bytes: Vec<u8> = reader
+        .samples::<i16>()
+        .map_while(Result::ok)
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+
+    let mut child = Command::new(VAD)
+        .args([
+            "--raw",
+            "s16le",
+            "--sample-rate",
+            &sample_rate,
+            "--format",
+            "csv",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .ok_or("missing stdin")?
+        .write_all(&bytes)?;
+    let output = child.wait_with_output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert_eq!(Some("start,end"), stdout.lines().next());
+    assert!(stdout.lines().count() > 1);
+
+    Ok(())
+}
+
+#[test]
+fn cli_rejects_invalid_threshold() -> Result<(), Box<dyn std::error::Error>> {
+    let output = Command::new(VAD)
+        .args(["tests/samples/sample.wav", "--threshold", "1.5"])
+        .output()?;
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn cli_rejects_file_options_for_directories() -> Result<(), Box<dyn std::error::Error>> {
+    let output = Command::new(VAD)
+        .args(["tests/samples", "--manifest", "manifest.jsonl"])
+        .args(["--format", "json"])
+        .output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)?.contains("cannot be used with"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_rejects_zero_channels_and_sample_rate() -> Result<(), Box<dyn std::error::Error>> {
+    for args in [
+        ["--sample-rate", "8000", "--channels", "0"],
+        ["--sample-rate", "0", "--channels", "1"],
+    ] {
+        let output = Command::new(VAD)
+            .args(["--raw", "s16le"])
+            .args(args)
+            .stdin(Stdio::null())
+            .output()?;
+        assert!(!output.status.success());
+        assert!(String::from_utf8(output.stderr)?.contains("invalid value"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use voice_activity_detector::{compact, CompactOptions, LabeledAudio, OffsetSegment};
+
+fn labels() -> Vec<LabeledAudio<i16>> {
+    vec![
+        LabeledAudio::NonSpeech(vec![0; 500]),
+        LabeledAudio::Speech(vec![8000; 300]),
+        LabeledAudio::Speech(vec![8000; 300]),
+        LabeledAudio::NonSpeech(vec![0; 1000]),
+        LabeledAudio::Speech(vec![-8000; 400]),
+        LabeledAudio::NonSpeech(vec![0; 200]),
+    ]
+}
+
+#[test]
+fn compact_crossfades_segments() {
+    let options = CompactOptions::builder().fade(100).build();
+    let compacted = compact(labels(), &options);
+
+    assert_eq!(600 + 400 - 100, compacted.samples.len());
+    assert_eq!(
+        &[
+            OffsetSegment {
+                compacted: 0,
+                original: 500,
+                len: 600
+            },
+            OffsetSegment {
+                compacted: 500,
+                original: 2100,
+                len: 400
+            },
+        ],
+        compacted.map.segments()
+    );
+
+    // The crossfade moves smoothly between the segments rather than jumping.
+    let largest_step = compacted
+        .samples
+        .windows(2)
+        .map(|pair| (i32::from(pair[1]) - i32::from(pair[0])).abs())
+        .max()
+        .unwrap();
+    assert!(largest_step <= 16000 / 100 + 1);
+    assert_eq!(8000, compacted.samples[499]);
+    assert_eq!(-8000, compacted.samples[600]);
+}
+
+#[test]
+fn compact_inserts_gap() {
+    let options = CompactOptions::builder().fade(50).gap(200).build();
+    let compacted = compact(labels(), &options);
+
+    assert_eq!(600 + 200 + 400, compacted.samples.len());
+    assert!(compacted.samples[600..800].iter().all(|&s| s == 0));
+    assert_eq!(800, compacted.map.segments()[1].compacted);
+
+    // The segments fade out into the gap and back in.
+    assert!(compacted.samples[599].abs() < 8000 / 50 + 1);
+    assert!(compacted.samples[800].abs() < 8000 / 50 + 1);
+    assert_eq!(8000, compacted.samples[549]);
+}
+
+#[test]
+fn offset_map_round_trip() {
+    let options = CompactOptions::builder().fade(0).build();
+    let map = compact(labels(), &options).map;
+
+    assert_eq!(500, map.to_original(0));
+    assert_eq!(1099, map.to_original(599));
+    assert_eq!(2100, map.to_original(600));
+    assert_eq!(2499, map.to_original(999));
+
+    for compacted in 0..1000 {
+        assert_eq!(compacted, map.to_compacted(map.to_original(compacted)));
+    }
+
+    // Removed audio maps to the point where it was cut.
+    assert_eq!(0, map.to_compacted(100));
+    assert_eq!(600, map.to_compacted(1500));
+    assert_eq!(1000, map.to_compacted(2550));
+}
+
+#[test]
+fn compact_without_speech() {
+    let labels = vec![LabeledAudio::NonSpeech(vec![0i16; 100])];
+    let compacted = compact(labels, &CompactOptions::default());
+
+    assert!(compacted.samples.is_empty());
+    assert!(compacted.map.segments().is_empty());
+    assert_eq!(0, compacted.map.to_compacted(50));
+}
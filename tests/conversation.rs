@@ -0,0 +1,165 @@
+use voice_activity_detector::{
+    ConversationAnalyzer, ConversationOptions, ConversationReport, IteratorExt, SilenceGap,
+    Speaker, SpeechSegment, Turn, VoiceActivityDetector,
+};
+
+fn report() -> ConversationReport {
+    let agent = [
+        SpeechSegment::new(0.5, 3.0),
+        SpeechSegment::new(3.5, 5.0),
+        SpeechSegment::new(9.0, 10.0),
+    ];
+    let customer = [SpeechSegment::new(5.5, 8.0), SpeechSegment::new(9.5, 12.0)];
+    ConversationReport::new(&agent, &customer, 13.0)
+}
+
+#[test]
+fn turns_are_ordered_by_start() {
+    let report = report();
+
+    let speakers: Vec<Speaker> = report.turns.iter().map(|turn| turn.speaker).collect();
+    assert_eq!(
+        vec![
+            Speaker::Agent,
+            Speaker::Agent,
+            Speaker::Customer,
+            Speaker::Agent,
+            Speaker::Customer,
+        ],
+        speakers
+    );
+    assert_eq!(13.0, report.duration);
+}
+
+#[test]
+fn overlaps_and_talk_time() {
+    let report = report();
+
+    assert_eq!(vec![SpeechSegment::new(9.5, 10.0)], report.overlaps);
+    assert_eq!(0.5, report.overlap_time);
+    assert_eq!(5.0, report.agent_talk_time);
+    assert_eq!(5.0, report.customer_talk_time);
+    assert_eq!(0.5, report.agent_talk_ratio);
+}
+
+#[test]
+fn response_latency_between_parties() {
+    let report = report();
+
+    // The customer answers the agent after 0.5 seconds, and interrupts the
+    // agent 0.5 seconds before the agent finishes.
+    assert_eq!(2, report.customer_response_latency.count);
+    assert_eq!(-0.5, report.customer_response_latency.min);
+    assert_eq!(0.5, report.customer_response_latency.max);
+    assert_eq!(1, report.agent_response_latency.count);
+    assert_eq!(1.0, report.agent_response_latency.mean);
+}
+
+#[test]
+fn longest_monologue_spans_consecutive_turns() {
+    let report = report();
+
+    assert_eq!(
+        Some(Turn {
+            speaker: Speaker::Agent,
+            start: 0.5,
+            end: 5.0
+        }),
+        report.longest_monologue
+    );
+}
+
+#[test]
+fn silence_gaps_between_speech() {
+    let report = report();
+
+    assert_eq!(
+        vec![
+            SilenceGap {
+                start: 3.0,
+                end: 3.5
+            },
+            SilenceGap {
+                start: 5.0,
+                end: 5.5
+            },
+            SilenceGap {
+                start: 8.0,
+                end: 9.0
+            },
+        ],
+        report.silence_gaps
+    );
+    assert_eq!(
+        Some(SilenceGap {
+            start: 8.0,
+            end: 9.0
+        }),
+        report.longest_silence
+    );
+}
+
+#[test]
+fn empty_conversation() {
+    let report = ConversationReport::new(&[], &[], 5.0);
+
+    assert!(report.turns.is_empty());
+    assert_eq!(0.0, report.agent_talk_ratio);
+    assert_eq!(None, report.longest_monologue);
+    assert_eq!(None, report.longest_silence);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn report_round_trips_through_json() -> Result<(), serde_json::Error> {
+    let report = report();
+    let json = serde_json::to_string(&report)?;
+    assert_eq!(report, serde_json::from_str(&json)?);
+    Ok(())
+}
+
+#[test]
+fn analyzer_matches_each_channel() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+    let silence = vec![0i16; samples.len()];
+
+    // The agent speaks on the left while the customer is silent on the right.
+    let stereo: Vec<i16> = samples
+        .iter()
+        .zip(&silence)
+        .flat_map(|(&l, &r)| [l, r])
+        .collect();
+    let options = ConversationOptions::builder()
+        .sample_rate(spec.sample_rate)
+        .chunk_size(256usize)
+        .build();
+    let mut analyzer = ConversationAnalyzer::new(options)?;
+    // Push odd-sized buffers, so frames are split across pushes.
+    for buffer in stereo.chunks(333) {
+        analyzer.push(buffer);
+    }
+    let report = analyzer.finish();
+
+    let mut vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+    let labels: Vec<_> = samples.iter().copied().label(&mut vad, 0.5, 3).collect();
+    let expected = SpeechSegment::from_labels(&labels, spec.sample_rate);
+
+    assert!(!expected.is_empty());
+    assert_eq!(expected.len(), report.turns.len());
+    assert!(report
+        .turns
+        .iter()
+        .all(|turn| turn.speaker == Speaker::Agent));
+    assert_eq!(1.0, report.agent_talk_ratio);
+    assert!(report.overlaps.is_empty());
+    assert_eq!(
+        samples.len() as f64 / f64::from(spec.sample_rate),
+        report.duration
+    );
+    Ok(())
+}
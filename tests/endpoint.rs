@@ -0,0 +1,134 @@
+use voice_activity_detector::{Endpoint, EndpointOptions, Endpointer, IteratorExt, LabeledAudio};
+
+fn speech(len: usize) -> LabeledAudio<i16> {
+    LabeledAudio::Speech(vec![0; len])
+}
+
+fn silence(len: usize) -> LabeledAudio<i16> {
+    LabeledAudio::NonSpeech(vec![0; len])
+}
+
+fn options() -> EndpointOptions {
+    EndpointOptions::builder()
+        .no_input_timeout(2.0)
+        .end_silence(0.5)
+        .max_length(3.0)
+        .build()
+}
+
+#[test]
+fn end_of_speech_after_trailing_silence() {
+    let labels = vec![silence(1000), speech(2000), silence(400), silence(100)];
+    let endpoints: Vec<Endpoint> = labels.into_iter().endpoints(1000, options()).collect();
+
+    assert_eq!(
+        vec![Endpoint::EndOfSpeech {
+            start: 1.0,
+            end: 3.0
+        }],
+        endpoints
+    );
+}
+
+#[test]
+fn short_pauses_do_not_end_speech() {
+    let labels = vec![speech(500), silence(400), speech(500), silence(500)];
+    let endpoints: Vec<Endpoint> = labels.into_iter().endpoints(1000, options()).collect();
+
+    assert_eq!(
+        vec![Endpoint::EndOfSpeech {
+            start: 0.0,
+            end: 1.4
+        }],
+        endpoints
+    );
+}
+
+#[test]
+fn no_input_after_timeout() {
+    let mut endpointer = Endpointer::new(1000, options());
+
+    assert_eq!(None, endpointer.push(&silence(1500)));
+    assert_eq!(
+        Some(Endpoint::NoInput { at: 2.0 }),
+        endpointer.push(&silence(500))
+    );
+    // The next turn starts after the endpoint.
+    assert_eq!(None, endpointer.push(&silence(1500)));
+    assert_eq!(
+        Some(Endpoint::NoInput { at: 4.0 }),
+        endpointer.push(&silence(500))
+    );
+}
+
+#[test]
+fn max_length_cuts_off_speech() {
+    let labels = vec![speech(1000), speech(1000), speech(1000), speech(500)];
+    let endpoints: Vec<Endpoint> = labels.into_iter().endpoints(1000, options()).collect();
+
+    assert_eq!(
+        vec![
+            Endpoint::MaxLength {
+                start: 0.0,
+                end: 3.0
+            },
+            Endpoint::EndOfSpeech {
+                start: 3.0,
+                end: 3.5
+            },
+        ],
+        endpoints
+    );
+}
+
+#[test]
+fn finish_ends_speech() {
+    let mut endpointer = Endpointer::new(1000, options());
+
+    assert_eq!(None, endpointer.push(&speech(500)));
+    assert_eq!(
+        Some(Endpoint::EndOfSpeech {
+            start: 0.0,
+            end: 0.5
+        }),
+        endpointer.finish()
+    );
+    assert_eq!(None, endpointer.finish());
+}
+
+#[test]
+fn reset_starts_a_new_turn() {
+    let mut endpointer = Endpointer::new(1000, options());
+
+    assert_eq!(None, endpointer.push(&silence(1500)));
+    endpointer.reset();
+    assert_eq!(None, endpointer.push(&silence(1500)));
+    assert_eq!(3000, endpointer.position());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn stream_matches_iterator() {
+    use futures::StreamExt as _;
+    use voice_activity_detector::StreamExt;
+
+    let labels = vec![
+        silence(2500),
+        speech(1000),
+        silence(600),
+        speech(3500),
+        silence(100),
+    ];
+    let expected: Vec<Endpoint> = labels
+        .clone()
+        .into_iter()
+        .endpoints(1000, options())
+        .collect();
+    let endpoints: Vec<Endpoint> = futures::stream::iter(labels)
+        .endpoints(1000, options())
+        .collect()
+        .await;
+
+    assert_eq!(expected, endpoints);
+    assert_eq!(3, endpoints.len());
+}
@@ -0,0 +1,84 @@
+use voice_activity_detector::{evaluate, Error, EvalOptions, SpeechSegment};
+
+fn assert_close(expected: f64, actual: f64) {
+    assert!(
+        (expected - actual).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn perfect_hypothesis() {
+    let reference = [SpeechSegment::new(1.0, 2.0), SpeechSegment::new(3.0, 4.0)];
+    let report = evaluate(&reference, &reference, &EvalOptions::default()).unwrap();
+
+    assert_close(1.0, report.precision);
+    assert_close(1.0, report.recall);
+    assert_close(1.0, report.f1);
+    assert_close(0.0, report.detection_error_rate);
+    assert_close(2.0, report.speech);
+    assert_close(2.0, report.non_speech);
+    assert_eq!(2, report.onset_latency.count);
+    assert_close(0.0, report.onset_latency.max);
+}
+
+#[test]
+fn late_and_early_hypothesis() {
+    let reference = [SpeechSegment::new(1.0, 2.0), SpeechSegment::new(3.0, 4.0)];
+    let hypothesis = [SpeechSegment::new(1.5, 2.0), SpeechSegment::new(2.5, 4.5)];
+    let options = EvalOptions::builder().duration(5.0).build();
+    let report = evaluate(&reference, &hypothesis, &options).unwrap();
+
+    assert_close(2.0, report.speech);
+    assert_close(3.0, report.non_speech);
+    assert_close(0.5, report.missed);
+    assert_close(1.0, report.false_alarm);
+    assert_close(1.5 / 2.5, report.precision);
+    assert_close(0.75, report.recall);
+    assert_close(0.25, report.miss_rate);
+    assert_close(1.0 / 3.0, report.false_alarm_rate);
+    assert_close(0.75, report.detection_error_rate);
+
+    assert_close(0.5, report.onset_latency.max);
+    assert_close(-0.5, report.onset_latency.min);
+    assert_close(0.0, report.onset_latency.mean);
+    assert_close(0.0, report.offset_latency.min);
+    assert_close(0.5, report.offset_latency.max);
+}
+
+#[test]
+fn collar_forgives_boundaries() {
+    let reference = [SpeechSegment::new(1.0, 2.0)];
+    let hypothesis = [SpeechSegment::new(1.1, 2.1)];
+
+    let strict = evaluate(&reference, &hypothesis, &EvalOptions::default()).unwrap();
+    assert!(strict.detection_error_rate > 0.0);
+
+    let options = EvalOptions::builder().collar(0.25).build();
+    let forgiving = evaluate(&reference, &hypothesis, &options).unwrap();
+    assert_close(0.0, forgiving.detection_error_rate);
+    assert_close(0.5, forgiving.speech);
+}
+
+#[test]
+fn overlapping_segments_are_merged() {
+    let reference = [SpeechSegment::new(1.0, 3.0), SpeechSegment::new(2.0, 4.0)];
+    let hypothesis = [SpeechSegment::new(1.0, 4.0)];
+    let report = evaluate(&reference, &hypothesis, &EvalOptions::default()).unwrap();
+
+    assert_close(3.0, report.speech);
+    assert_close(1.0, report.f1);
+    assert_eq!(1, report.onset_latency.count);
+}
+
+#[test]
+fn rejects_non_positive_frame() {
+    let reference = [SpeechSegment::new(1.0, 2.0)];
+    for frame in [0.0, -0.01, f64::NAN] {
+        let options = EvalOptions::builder().frame(frame).build();
+        assert!(matches!(
+            evaluate(&reference, &reference, &options),
+            Err(Error::EvalConfigError { .. })
+        ));
+    }
+}
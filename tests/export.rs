@@ -0,0 +1,86 @@
+use voice_activity_detector::{
+    parse_audacity, parse_rttm, write_audacity, write_rttm, write_srt, write_webvtt, LabeledAudio,
+    SpeechSegment,
+};
+
+fn segments() -> Vec<SpeechSegment> {
+    vec![
+        SpeechSegment::new(0.5, 1.25),
+        SpeechSegment::new(3.0, 4.5),
+        SpeechSegment::new(3725.125, 3726.0),
+    ]
+}
+
+fn assert_close(expected: &[SpeechSegment], actual: &[SpeechSegment]) {
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(actual) {
+        assert!((expected.start - actual.start).abs() < 1e-3);
+        assert!((expected.end - actual.end).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn srt_export() -> Result<(), Box<dyn std::error::Error>> {
+    let mut output = Vec::new();
+    write_srt(&mut output, &segments())?;
+    let output = String::from_utf8(output)?;
+
+    assert!(output.starts_with("1\n00:00:00,500 --> 00:00:01,250\nspeech\n\n2\n"));
+    assert!(output.contains("3\n01:02:05,125 --> 01:02:06,000\nspeech\n"));
+
+    Ok(())
+}
+
+#[test]
+fn webvtt_export() -> Result<(), Box<dyn std::error::Error>> {
+    let mut output = Vec::new();
+    write_webvtt(&mut output, &segments())?;
+    let output = String::from_utf8(output)?;
+
+    assert!(output.starts_with("WEBVTT\n\n00:00:00.500 --> 00:00:01.250\nspeech\n"));
+
+    Ok(())
+}
+
+#[test]
+fn audacity_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let mut output = Vec::new();
+    write_audacity(&mut output, &segments())?;
+    let parsed = parse_audacity(&String::from_utf8(output)?)?;
+    assert_close(&segments(), &parsed);
+
+    // Spectral selection lines and point labels are skipped.
+    let parsed = parse_audacity("1.0\t2.0\tword\n\\\t100.0\t2000.0\n3.0\t3.0\tpoint\n")?;
+    assert_close(&[SpeechSegment::new(1.0, 2.0)], &parsed);
+
+    Ok(())
+}
+
+#[test]
+fn rttm_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let mut output = Vec::new();
+    write_rttm(&mut output, &segments(), "call")?;
+    write_rttm(&mut output, &[SpeechSegment::new(9.0, 10.0)], "other")?;
+    let output = String::from_utf8(output)?;
+
+    assert_close(&segments(), &parse_rttm(&output, Some("call"))?);
+    assert_eq!(4, parse_rttm(&output, None)?.len());
+    assert!(parse_rttm("SPEAKER call 1 zero 1.0", None).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn segments_from_labels() {
+    let labels = [
+        LabeledAudio::Speech(vec![0i16; 4000]),
+        LabeledAudio::NonSpeech(vec![0i16; 8000]),
+        LabeledAudio::Speech(vec![0i16; 8000]),
+    ];
+
+    let segments = SpeechSegment::from_labels(&labels, 8000);
+    assert_eq!(
+        vec![SpeechSegment::new(0.0, 0.5), SpeechSegment::new(1.5, 2.5)],
+        segments
+    );
+}
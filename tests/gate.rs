@@ -0,0 +1,93 @@
+use voice_activity_detector::{GateOptions, IteratorExt, LabeledAudio};
+
+fn labels() -> Vec<LabeledAudio<i16>> {
+    vec![
+        LabeledAudio::NonSpeech(vec![1000; 100]),
+        LabeledAudio::Speech(vec![1000; 100]),
+        LabeledAudio::NonSpeech(vec![1000; 100]),
+    ]
+}
+
+#[test]
+fn gate_preserves_length_and_speech() {
+    let options = GateOptions::builder()
+        .gain(0.0)
+        .attack(0)
+        .release(0)
+        .build();
+    let gated: Vec<i16> = labels().into_iter().gate(options).collect();
+
+    assert_eq!(300, gated.len());
+    assert!(gated[..100].iter().all(|&s| s == 0));
+    assert!(gated[100..200].iter().all(|&s| s == 1000));
+    assert!(gated[200..].iter().all(|&s| s == 0));
+}
+
+#[test]
+fn gate_attenuates_non_speech() {
+    let options = GateOptions::builder()
+        .gain(0.5)
+        .attack(0)
+        .release(0)
+        .build();
+    let gated: Vec<i16> = labels().into_iter().gate(options).collect();
+
+    assert!(gated[..100].iter().all(|&s| s == 500));
+    assert!(gated[100..200].iter().all(|&s| s == 1000));
+}
+
+#[test]
+fn gate_ramps_at_transitions() {
+    let options = GateOptions::builder()
+        .gain(0.0)
+        .attack(10)
+        .release(50)
+        .build();
+    let gated: Vec<i16> = labels().into_iter().gate(options).collect();
+
+    // The attack ramps up over 10 samples at the start of speech.
+    let attack = &gated[100..110];
+    assert!(attack.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(gated[110..200].iter().all(|&s| s == 1000));
+
+    // The release ramps down over 50 samples after speech.
+    let release = &gated[200..250];
+    assert!(release.windows(2).all(|pair| pair[0] > pair[1]));
+    assert!(gated[250..].iter().all(|&s| s == 0));
+}
+
+#[test]
+fn gate_f32_samples() {
+    let labels = vec![
+        LabeledAudio::Speech(vec![0.5f32; 4]),
+        LabeledAudio::NonSpeech(vec![0.5f32; 4]),
+    ];
+    let options = GateOptions::builder()
+        .gain(0.25)
+        .attack(0)
+        .release(0)
+        .build();
+    let gated: Vec<f32> = labels.into_iter().gate(options).collect();
+
+    assert_eq!(vec![0.5, 0.5, 0.5, 0.5, 0.125, 0.125, 0.125, 0.125], gated);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn gate_stream() {
+    use futures::StreamExt as _;
+    use voice_activity_detector::StreamExt;
+
+    let options = GateOptions::builder()
+        .gain(0.0)
+        .attack(10)
+        .release(50)
+        .build();
+    let expected: Vec<i16> = labels().into_iter().gate(options).collect();
+    let gated: Vec<i16> = futures::stream::iter(labels())
+        .gate(options)
+        .collect()
+        .await;
+
+    assert_eq!(expected, gated);
+}
@@ -0,0 +1,78 @@
+use voice_activity_detector::{IteratorExt, LabelControl, Labeler, VoiceActivityDetector};
+
+#[test]
+fn control_changes_threshold_at_next_chunk() {
+    let mut labeler = Labeler::new(0.5, 0);
+    let control = labeler.control();
+
+    let labels: Vec<_> = labeler.push(vec![0], 0.6).collect();
+    assert!(labels[0].is_speech());
+    labeler.finish().for_each(drop);
+
+    control.set_threshold(0.9);
+    assert_eq!(0.9, control.threshold());
+    let labels: Vec<_> = labeler.push(vec![1], 0.6).collect();
+    assert!(!labels[0].is_speech());
+}
+
+#[test]
+fn shrinking_padding_keeps_buffered_chunks() {
+    let mut labeler = Labeler::new(0.5, 4);
+    let control = labeler.control();
+
+    let mut labels = Vec::new();
+    for index in 0..4 {
+        labels.extend(labeler.push(vec![index], 0.1));
+    }
+    assert!(labels.is_empty());
+
+    // The chunks held back for the old padding are released, not dropped.
+    control.set_padding_chunks(1);
+    for index in 4..6 {
+        labels.extend(labeler.push(vec![index], 0.1));
+    }
+    labels.extend(labeler.finish());
+
+    let indices: Vec<_> = labels.iter().map(|audio| audio.as_slice()[0]).collect();
+    assert_eq!((0..6).collect::<Vec<_>>(), indices);
+    assert!(labels.iter().all(|audio| !audio.is_speech()));
+}
+
+#[test]
+fn control_is_shared_between_threads() {
+    let control = LabelControl::new(0.5, 3);
+    let remote = control.clone();
+    std::thread::spawn(move || {
+        remote.set_threshold(0.25);
+        remote.set_padding_chunks(7);
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(0.25, control.threshold());
+    assert_eq!(7, control.padding_chunks());
+}
+
+#[test]
+fn control_running_label_iterator() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let samples: Vec<i16> = reader.samples::<i16>().collect::<Result<_, _>>()?;
+
+    let vad = VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()?;
+    let mut labels = samples.iter().copied().label(vad, 0.5, 3);
+    let control = labels.control();
+
+    let before = labels.by_ref().take(20).count();
+    control.set_threshold(1.1);
+    control.set_padding_chunks(0);
+    let after: Vec<_> = labels.collect();
+
+    // No speech can reach the new threshold once the buffered chunks are released.
+    assert!(after.iter().skip(4).all(|audio| !audio.is_speech()));
+    let total: usize = after.iter().map(|audio| audio.len()).sum::<usize>() + before * 256;
+    assert_eq!(samples.len() - samples.len() % 256, total);
+    Ok(())
+}
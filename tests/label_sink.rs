@@ -0,0 +1,31 @@
+#![cfg(feature = "async")]
+
+use futures::StreamExt;
+use voice_activity_detector::{LabelSink, LabeledAudio, VoiceActivityDetector};
+
+#[tokio::test]
+async fn forward_into_label_sink() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+
+    let (sink, receiver) = LabelSink::new(vad, 0.5, 10, 4);
+    let buffers = samples.chunks(160).map(|buffer| Ok(buffer.to_vec()));
+
+    let (forwarded, labels) = futures::join!(
+        futures::stream::iter(buffers).forward(sink),
+        receiver.collect::<Vec<LabeledAudio<i16>>>(),
+    );
+    forwarded?;
+
+    let labeled: usize = labels.iter().map(|audio| audio.iter().count()).sum();
+    assert_eq!(samples.len() / 256 * 256, labeled);
+    assert!(labels.iter().any(LabeledAudio::is_speech));
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use voice_activity_detector::{LabeledAudio, Labeler};
+
+/// Labels chunks numbered by their index, returning the indices of speech chunks.
+fn speech(labeler: &mut Labeler<usize>, probabilities: &[f32]) -> Vec<usize> {
+    let mut labels = Vec::new();
+    for (index, &probability) in probabilities.iter().enumerate() {
+        labels.extend(labeler.push(vec![index], probability));
+    }
+    labels.extend(labeler.finish());
+
+    let indices: Vec<_> = labels.iter().map(|audio| audio.as_slice()[0]).collect();
+    assert_eq!((0..probabilities.len()).collect::<Vec<_>>(), indices);
+
+    labels
+        .into_iter()
+        .filter(LabeledAudio::is_speech)
+        .map(|audio| audio.as_slice()[0])
+        .collect()
+}
+
+#[test]
+fn labeler_padding() {
+    let probabilities = [0.1, 0.1, 0.1, 0.9, 0.9, 0.1, 0.1, 0.1, 0.1];
+
+    assert_eq!(
+        vec![3, 4, 5],
+        speech(&mut Labeler::new(0.5, 0), &probabilities)
+    );
+    assert_eq!(
+        vec![2, 3, 4, 5],
+        speech(&mut Labeler::new(0.5, 1), &probabilities)
+    );
+    assert_eq!(
+        vec![1, 2, 3, 4, 5, 6],
+        speech(&mut Labeler::new(0.5, 2), &probabilities)
+    );
+    assert!(speech(&mut Labeler::new(0.95, 2), &probabilities).is_empty());
+}
+
+#[test]
+fn labeler_holds_back_padding() {
+    let mut labeler = Labeler::new(0.5, 2);
+
+    assert_eq!(0, labeler.push(vec![0], 0.1).count());
+    assert_eq!(0, labeler.push(vec![1], 0.1).count());
+    let labels: Vec<_> = labeler.push(vec![2], 0.1).collect();
+    assert_eq!(1, labels.len());
+    assert!(!labels[0].is_speech());
+
+    // Speech flushes the padding held back before it.
+    let labels: Vec<_> = labeler.push(vec![3], 0.9).collect();
+    assert_eq!(3, labels.len());
+    assert!(labels.iter().all(LabeledAudio::is_speech));
+
+    let labels: Vec<_> = labeler.finish().collect();
+    assert!(labels.is_empty());
+}
+
+#[test]
+fn labeler_reusable_after_finish() {
+    let mut labeler = Labeler::new(0.5, 1);
+    let probabilities = [0.1, 0.9, 0.1, 0.1];
+
+    let first = speech(&mut labeler, &probabilities);
+    let second = speech(&mut labeler, &probabilities);
+    assert_eq!(first, second);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "async-tokio")]
+
+use tokio_stream::StreamExt;
+use voice_activity_detector::{IteratorExt, StreamExt as _, VoiceActivityDetector};
+
+#[tokio::test]
+async fn offload_matches_sequential() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let mut vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+
+    let expected: Vec<f32> = samples
+        .iter()
+        .copied()
+        .predict(&mut vad)
+        .map(|(_, probability)| probability)
+        .collect();
+
+    vad.reset();
+    let mut predictions = tokio_stream::iter(samples).predict_offload(vad, 4)?;
+    let mut actual = Vec::new();
+    while let Some((chunk, probability)) = predictions.next().await {
+        assert_eq!(chunk.len(), 256);
+        actual.push(probability);
+    }
+
+    assert_eq!(expected, actual);
+
+    // The detector is handed back once the inference thread has stopped.
+    let mut vad = predictions.into_vad();
+    vad.reset();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn offload_label_stream() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+
+    let total = samples.len() / 256 * 256;
+    let mut labels = tokio_stream::iter(samples).label_offload(vad, 0.5, 10, 2)?;
+    let mut labeled = 0;
+    while let Some(audio) = labels.next().await {
+        labeled += audio.iter().count();
+    }
+
+    assert_eq!(total, labeled);
+
+    // The detector is still available after the stream has ended.
+    let mut vad = labels.into_vad();
+    vad.reset();
+
+    Ok(())
+}
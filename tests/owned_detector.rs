@@ -0,0 +1,72 @@
+use voice_activity_detector::{IteratorExt, LabelIterator, VoiceActivityDetector};
+
+/// Returns a label iterator that owns its voice activity detector.
+fn labels(
+    samples: Vec<i16>,
+    sample_rate: u32,
+) -> Result<
+    LabelIterator<i16, std::vec::IntoIter<i16>, VoiceActivityDetector>,
+    Box<dyn std::error::Error>,
+> {
+    let vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(sample_rate)
+        .build()?;
+
+    Ok(samples.into_iter().label(vad, 0.5, 10))
+}
+
+#[test]
+fn owned_label_iterator() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let mut labels = labels(samples.clone(), spec.sample_rate)?;
+    let speech = labels.by_ref().filter(|audio| audio.is_speech()).count();
+    assert!(speech > 0);
+
+    // The detector can be recovered and reused once the iterator is done.
+    let mut vad = labels.into_vad();
+    vad.reset();
+    let again = samples
+        .into_iter()
+        .label(&mut vad, 0.5, 10)
+        .filter(|audio| audio.is_speech())
+        .count();
+    assert_eq!(speech, again);
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn owned_label_stream_spawned() -> Result<(), Box<dyn std::error::Error>> {
+    use tokio_stream::StreamExt;
+    use voice_activity_detector::StreamExt as _;
+
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+
+    let mut labels = tokio_stream::iter(samples).label(Box::new(vad), 0.5, 10);
+    let speech = tokio::spawn(async move {
+        let mut speech = 0;
+        while let Some(audio) = labels.next().await {
+            if audio.is_speech() {
+                speech += 1;
+            }
+        }
+        speech
+    })
+    .await?;
+
+    assert!(speech > 0);
+
+    Ok(())
+}
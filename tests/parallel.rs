@@ -0,0 +1,67 @@
+use voice_activity_detector::{
+    evaluate, label_parallel, predict_parallel, EvalOptions, ParallelOptions, ProbabilityTrace,
+    VoiceActivityDetector,
+};
+
+fn samples() -> Vec<i16> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav").unwrap();
+    reader.samples::<i16>().map(Result::unwrap).collect()
+}
+
+fn vad() -> VoiceActivityDetector {
+    VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()
+        .unwrap()
+}
+
+fn options() -> ParallelOptions {
+    ParallelOptions::builder()
+        .window(5.0)
+        .overlap(2.0)
+        .search(0.5)
+        .workers(4)
+        .build()
+}
+
+#[test]
+fn parallel_within_tolerance_of_sequential() {
+    let samples = samples();
+    let sequential = ProbabilityTrace::predict(&mut vad(), samples.iter().copied());
+    let parallel = predict_parallel(&vad(), &samples, &options());
+
+    assert_eq!(sequential.probabilities.len(), parallel.probabilities.len());
+    let mean_difference = sequential
+        .probabilities
+        .iter()
+        .zip(&parallel.probabilities)
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f32>()
+        / sequential.probabilities.len() as f32;
+    assert!(mean_difference < 0.02, "mean difference {mean_difference}");
+
+    let reference = sequential.segments(0.5, 3, 0.0);
+    let hypothesis = parallel.segments(0.5, 3, 0.0);
+    let report = evaluate(&reference, &hypothesis, &EvalOptions::default()).unwrap();
+    assert!(report.f1 > 0.95, "f1 {}", report.f1);
+}
+
+#[test]
+fn parallel_single_window_is_sequential() {
+    let samples = samples();
+    let sequential = ProbabilityTrace::predict(&mut vad(), samples.iter().copied());
+    let options = ParallelOptions::builder().window(3600.0).build();
+
+    assert_eq!(sequential, predict_parallel(&vad(), &samples, &options));
+}
+
+#[test]
+fn label_parallel_covers_recording() {
+    let samples = samples();
+    let labels = label_parallel(&vad(), &samples, 0.5, 3, &options());
+
+    let labeled: Vec<i16> = labels.into_iter().flatten().collect();
+    assert_eq!(&samples[..labeled.len()], labeled.as_slice());
+    assert_eq!(samples.len() - samples.len() % 256, labeled.len());
+}
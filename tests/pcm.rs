@@ -0,0 +1,141 @@
+use std::io::{Cursor, ErrorKind};
+
+use voice_activity_detector::{
+    Endianness, Error, IteratorExt, LabeledAudio, PcmFormat, PcmReader, PcmWriter, Sample,
+    VoiceActivityDetector,
+};
+
+#[test]
+fn pcm_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let format = PcmFormat::builder()
+        .sample_rate(8000u32)
+        .endianness(Endianness::Big)
+        .channels(2)
+        .build();
+    let samples: Vec<i16> = (-500..500).map(|sample| sample * 31).collect();
+
+    let mut writer = PcmWriter::new(Vec::new(), format)?;
+    writer.write_audio(&LabeledAudio::Speech(samples.clone()))?;
+    let bytes = writer.into_inner();
+    assert_eq!(bytes.len(), samples.len() * 2 * 2);
+
+    let decoded =
+        PcmReader::<_, i16>::new(Cursor::new(bytes), format)?.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(samples, decoded);
+
+    Ok(())
+}
+
+#[test]
+fn pcm_reader_selects_channel() -> Result<(), Box<dyn std::error::Error>> {
+    let format = PcmFormat::builder()
+        .sample_rate(8000u32)
+        .channels(2)
+        .channel(1)
+        .build();
+    let bytes: Vec<u8> = [1i16, -1, 2, -2, 3, -3]
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+
+    let decoded =
+        PcmReader::<_, i16>::new(bytes.as_slice(), format)?.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(vec![-1, -2, -3], decoded);
+
+    Ok(())
+}
+
+#[test]
+fn pcm_reader_keeps_error() {
+    let format = PcmFormat::builder().sample_rate(8000u32).build();
+    // Three bytes is one and a half 16 bit samples.
+    let mut reader = PcmReader::<_, i16>::new([0u8, 1, 2].as_slice(), format).unwrap();
+
+    assert_eq!(1, reader.samples().count());
+    let error = reader.take_error().unwrap_err();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+}
+
+#[test]
+fn pcm_format_is_validated() {
+    let no_channels = PcmFormat::builder()
+        .sample_rate(8000u32)
+        .channels(0)
+        .build();
+    assert!(matches!(
+        PcmReader::<_, i16>::new([].as_slice(), no_channels),
+        Err(Error::PcmFormatError { .. })
+    ));
+
+    let missing_channel = PcmFormat::builder()
+        .sample_rate(8000u32)
+        .channels(2)
+        .channel(2)
+        .build();
+    assert!(matches!(
+        PcmWriter::<_, i16>::new(Vec::new(), missing_channel),
+        Err(Error::PcmFormatError { .. })
+    ));
+}
+
+#[test]
+fn pcm_u8_is_centred() -> Result<(), Box<dyn std::error::Error>> {
+    let format = PcmFormat::builder().sample_rate(8000u32).build();
+    let decoded = PcmReader::<_, u8>::new([128u8, 0, 255].as_slice(), format)?
+        .map(|sample| sample.map(Sample::to_f32))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(vec![0.0, -1.0, 127.0 / 128.0], decoded);
+    Ok(())
+}
+
+#[test]
+fn pcm_reader_label_iterator() -> Result<(), Box<dyn std::error::Error>> {
+    let mut wav = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = wav.spec();
+    let bytes: Vec<u8> = wav
+        .samples::<i16>()
+        .map_while(Result::ok)
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+
+    let format = PcmFormat::builder().sample_rate(spec.sample_rate).build();
+    let mut reader = PcmReader::<_, i16>::new(bytes.as_slice(), format)?;
+    let mut vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(format.sample_rate)
+        .build()?;
+
+    let mut writer = PcmWriter::new(Vec::new(), format)?;
+    for audio in reader.samples().label(&mut vad, 0.5, 10) {
+        if audio.is_speech() {
+            writer.write_audio(&audio)?;
+        }
+    }
+    reader.take_error()?;
+
+    assert!(!writer.into_inner().is_empty());
+
+    Ok(())
+}
+
+#[cfg(feature = "async-tokio")]
+#[tokio::test]
+async fn async_pcm_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    use tokio_stream::StreamExt;
+    use voice_activity_detector::{AsyncPcmReader, AsyncPcmWriter};
+
+    let format = PcmFormat::builder().sample_rate(16000u32).build();
+    let samples: Vec<f32> = (0..1000).map(|sample| sample as f32 / 1000.0).collect();
+
+    let mut writer = AsyncPcmWriter::new(Vec::new(), format)?;
+    writer.write_samples(&samples).await?;
+    let bytes = writer.into_inner();
+
+    let mut reader = AsyncPcmReader::<_, f32>::new(bytes.as_slice(), format)?;
+    let decoded: Vec<f32> = reader.samples().collect().await;
+    reader.take_error()?;
+    assert_eq!(samples, decoded);
+
+    Ok(())
+}
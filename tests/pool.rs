@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use voice_activity_detector::{Error, IteratorExt, VadPool};
+
+fn pool(capacity: usize) -> VadPool {
+    VadPool::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .capacity(capacity)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn pool_config_error() {
+    let result = VadPool::builder()
+        .sample_rate(16000)
+        .chunk_size(256usize)
+        .capacity(4usize)
+        .build();
+    assert!(matches!(result, Err(Error::VadConfigError { .. })));
+}
+
+#[test]
+fn pool_capacity_and_metrics() {
+    let pool = pool(2);
+    let first = pool.acquire();
+    let second = pool.try_acquire().unwrap();
+    assert!(pool.try_acquire().is_none());
+
+    let metrics = pool.metrics();
+    assert_eq!(2, metrics.created);
+    assert_eq!(2, metrics.in_use);
+    assert_eq!(0, metrics.idle);
+    assert_eq!(2, metrics.acquired);
+
+    drop(first);
+    drop(second);
+    let metrics = pool.metrics();
+    assert_eq!(0, metrics.in_use);
+    assert_eq!(2, metrics.idle);
+
+    // Idle detectors are reused rather than created.
+    let _vad = pool.acquire();
+    assert_eq!(2, pool.metrics().created);
+}
+
+#[test]
+fn pool_returns_reset_detectors() {
+    let pool = pool(1);
+    let silence = [0i16; 256];
+
+    let mut vad = pool.acquire();
+    let fresh = vad.predict(silence);
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav").unwrap();
+    let samples: Vec<i16> = reader.samples::<i16>().map(Result::unwrap).collect();
+    samples.into_iter().predict(&mut *vad).for_each(drop);
+    drop(vad);
+
+    let mut vad = pool.acquire();
+    assert_eq!(fresh, vad.predict(silence));
+}
+
+#[test]
+fn pool_blocking_acquire_waits_for_release() {
+    let pool = pool(1);
+    let vad = pool.acquire();
+
+    let waiter = {
+        let pool = pool.clone();
+        std::thread::spawn(move || {
+            let _vad = pool.acquire();
+        })
+    };
+    while pool.metrics().waiting == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    drop(vad);
+    waiter.join().unwrap();
+    let metrics = pool.metrics();
+    assert_eq!(1, metrics.waits);
+    assert_eq!(0, metrics.waiting);
+    assert!(metrics.wait_time > Duration::ZERO);
+}
+
+#[tokio::test]
+async fn pool_async_acquire() {
+    let pool = pool(1);
+    let vad = pool.acquire();
+
+    let waiter = tokio::spawn({
+        let pool = pool.clone();
+        async move {
+            let mut vad = pool.acquire_async().await;
+            vad.predict([0i16; 256])
+        }
+    });
+    tokio::task::yield_now().await;
+
+    drop(vad);
+    waiter.await.unwrap();
+    assert_eq!(0, pool.metrics().in_use);
+}
@@ -0,0 +1,31 @@
+use voice_activity_detector::{IteratorExt, Predictor, Sample};
+
+/// Treats loud chunks as speech, so labeling can be tested without the model.
+struct Loudness {
+    chunks: usize,
+}
+
+impl Predictor for Loudness {
+    fn chunk_size(&self) -> usize {
+        4
+    }
+
+    fn predict_chunk<S: Sample>(&mut self, chunk: &[S]) -> f32 {
+        self.chunks += 1;
+        chunk.iter().map(|s| s.to_f32().abs()).fold(0.0, f32::max)
+    }
+}
+
+#[test]
+fn custom_predictor_drives_label_iterator() {
+    let samples: Vec<f32> = [0.0, 0.0, 0.9, 0.0, 0.0]
+        .into_iter()
+        .flat_map(|level| [level; 4])
+        .collect();
+
+    let mut iter = samples.into_iter().label(Loudness { chunks: 0 }, 0.5, 1);
+    let labels: Vec<bool> = iter.by_ref().map(|label| label.is_speech()).collect();
+
+    assert_eq!(vec![false, true, true, true, false], labels);
+    assert_eq!(5, iter.into_vad().chunks);
+}
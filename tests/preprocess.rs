@@ -0,0 +1,140 @@
+use std::f32::consts::PI;
+
+use voice_activity_detector::{Gain, IteratorExt, Preprocessor, VoiceActivityDetector};
+
+fn sine(frequency: f32, amplitude: f32, sample_rate: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| amplitude * (2.0 * PI * frequency * i as f32 / sample_rate).sin())
+        .collect()
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+#[test]
+fn dc_blocker_removes_offset() {
+    let mut preprocessor = Preprocessor::new().dc_blocker(0.995);
+    let mut samples = vec![0.5f32; 8000];
+    preprocessor.process(&mut samples, 8000);
+    assert!(samples[7999].abs() < 0.01);
+}
+
+#[test]
+fn high_pass_removes_rumble() {
+    let mut preprocessor = Preprocessor::new().high_pass(100.0);
+
+    let mut rumble = sine(20.0, 0.5, 16000.0, 16000);
+    preprocessor.process(&mut rumble, 16000);
+    assert!(rms(&rumble[8000..]) < 0.05);
+
+    preprocessor.reset();
+    let mut voice = sine(1000.0, 0.5, 16000.0, 16000);
+    preprocessor.process(&mut voice, 16000);
+    assert!((rms(&voice[8000..]) - 0.5 / 2f32.sqrt()).abs() < 0.02);
+}
+
+#[test]
+fn filter_state_carries_across_chunks() {
+    let signal: Vec<f32> = sine(50.0, 0.3, 8000.0, 1024)
+        .into_iter()
+        .zip(sine(700.0, 0.2, 8000.0, 1024))
+        .map(|(a, b)| a + b + 0.1)
+        .collect();
+
+    let mut whole = signal.clone();
+    Preprocessor::new()
+        .dc_blocker(0.99)
+        .high_pass(80.0)
+        .process(&mut whole, 8000);
+
+    let mut chunked = signal;
+    let mut preprocessor = Preprocessor::new().dc_blocker(0.99).high_pass(80.0);
+    for chunk in chunked.chunks_mut(256) {
+        preprocessor.process(chunk, 8000);
+    }
+
+    assert_eq!(whole, chunked);
+}
+
+#[test]
+fn gain_normalizes_level() {
+    let quiet = sine(440.0, 0.01, 8000.0, 512);
+
+    let mut rms_normalized = quiet.clone();
+    Preprocessor::new()
+        .gain(Gain::Rms {
+            target: 0.1,
+            max_gain: 100.0,
+        })
+        .process(&mut rms_normalized, 8000);
+    assert!((rms(&rms_normalized) - 0.1).abs() < 1e-3);
+
+    let mut peak_normalized = quiet.clone();
+    Preprocessor::new()
+        .gain(Gain::Peak {
+            target: 0.5,
+            max_gain: 100.0,
+        })
+        .process(&mut peak_normalized, 8000);
+    let peak = peak_normalized.iter().fold(0f32, |p, s| p.max(s.abs()));
+    assert!((peak - 0.5).abs() < 1e-3);
+
+    let mut limited = quiet.clone();
+    Preprocessor::new()
+        .gain(Gain::Rms {
+            target: 0.1,
+            max_gain: 2.0,
+        })
+        .process(&mut limited, 8000);
+    assert!((rms(&limited) - 2.0 * rms(&quiet)).abs() < 1e-4);
+}
+
+#[test]
+fn agc_converges_gradually() {
+    let mut preprocessor = Preprocessor::new().gain(Gain::Agc {
+        target: 0.1,
+        max_gain: 100.0,
+        rate: 0.25,
+    });
+
+    let mut levels = Vec::new();
+    for _ in 0..40 {
+        let mut chunk = sine(440.0, 0.01, 8000.0, 512);
+        preprocessor.process(&mut chunk, 8000);
+        levels.push(rms(&chunk));
+    }
+
+    assert!(levels[0] < 0.05);
+    assert!(levels.windows(2).all(|pair| pair[1] >= pair[0]));
+    assert!((levels[39] - 0.1).abs() < 1e-3);
+}
+
+#[test]
+fn preprocessing_does_not_change_labeled_audio() {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav").unwrap();
+    let samples: Vec<i16> = reader.samples::<i16>().map(Result::unwrap).collect();
+
+    let vad = VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .preprocessor(
+            Preprocessor::new()
+                .dc_blocker(0.995)
+                .high_pass(80.0)
+                .gain(Gain::Rms {
+                    target: 0.1,
+                    max_gain: 10.0,
+                }),
+        )
+        .build()
+        .unwrap();
+
+    let labeled: Vec<i16> = samples
+        .iter()
+        .copied()
+        .label(vad, 0.5, 3)
+        .flatten()
+        .collect();
+    assert_eq!(&samples[..labeled.len()], labeled.as_slice());
+}
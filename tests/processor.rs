@@ -0,0 +1,33 @@
+use voice_activity_detector::{IteratorExt, LabeledAudio, VadProcessor, VoiceActivityDetector};
+
+#[test]
+fn processor_matches_label_iterator() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let mut vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+
+    let expected: Vec<LabeledAudio<i16>> =
+        samples.iter().copied().label(&mut vad, 0.5, 10).collect();
+
+    vad.reset();
+    let mut processor = VadProcessor::new(&mut vad, 0.5, 10);
+    let mut actual = Vec::new();
+    // Push buffers that do not line up with the chunk size, as an audio callback would.
+    for buffer in samples.chunks(160) {
+        actual.extend(processor.push(buffer));
+    }
+    actual.extend(processor.finish());
+
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(&actual) {
+        assert_eq!(expected.is_speech(), actual.is_speech());
+        assert!(expected.iter().eq(actual.iter()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use voice_activity_detector::{Error, RegistryOptions, VadRegistry};
+
+fn samples() -> Vec<i16> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav").unwrap();
+    reader.samples::<i16>().map(Result::unwrap).collect()
+}
+
+fn options() -> RegistryOptions {
+    RegistryOptions::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .padding_chunks(3)
+        .build()
+}
+
+/// Labels, as speech flags and lengths, for comparison between runs.
+type Labels = Vec<(bool, usize)>;
+
+fn summarize(
+    labels: impl IntoIterator<Item = voice_activity_detector::LabeledAudio<i16>>,
+) -> Labels {
+    labels
+        .into_iter()
+        .map(|audio| (audio.is_speech(), audio.len()))
+        .collect()
+}
+
+#[test]
+fn registry_config_error() {
+    let options = RegistryOptions::builder()
+        .sample_rate(16000)
+        .chunk_size(256usize)
+        .build();
+    assert!(matches!(
+        VadRegistry::<u32, i16>::new(options),
+        Err(Error::VadConfigError { .. })
+    ));
+}
+
+#[test]
+fn registry_streams_are_independent() {
+    let samples = samples();
+    let mut registry = VadRegistry::<&str, i16>::new(options()).unwrap();
+
+    // Interleave packets of two calls; each should label like it was alone.
+    let mut first = Labels::new();
+    let mut second = Labels::new();
+    for packet in samples.chunks(160) {
+        first.extend(summarize(registry.push("first", packet)));
+        second.extend(summarize(registry.push("second", packet)));
+    }
+    assert_eq!(2, registry.len());
+    first.extend(summarize(registry.finish(&"first").unwrap()));
+    second.extend(summarize(registry.finish(&"second").unwrap()));
+
+    assert_eq!(first, second);
+    assert!(registry.is_empty());
+}
+
+#[test]
+fn registry_evicts_idle_streams() {
+    let options = RegistryOptions::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .ttl(Duration::from_millis(20))
+        .build();
+    let mut registry = VadRegistry::<u32, i16>::new(options).unwrap();
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    registry.on_evict({
+        let evicted = evicted.clone();
+        move |key, labels| evicted.lock().unwrap().push((key, labels.len()))
+    });
+
+    registry.push(1, &[0; 1000]);
+    registry.push(2, &[0; 1000]);
+    std::thread::sleep(Duration::from_millis(30));
+    registry.push(2, &[0; 10]);
+
+    assert!(!registry.contains(&1));
+    assert!(registry.contains(&2));
+    let evicted = evicted.lock().unwrap();
+    assert_eq!(1, evicted.len());
+    assert_eq!(1, evicted[0].0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn registry_snapshot_resumes_streams() {
+    let samples = samples();
+    let (head, tail) = samples.split_at(samples.len() / 2 + 100);
+
+    let mut registry = VadRegistry::<String, i16>::new(options()).unwrap();
+    let mut expected = summarize(registry.push("call".into(), &samples));
+    expected.extend(summarize(registry.finish(&"call".into()).unwrap()));
+
+    let mut registry = VadRegistry::<String, i16>::new(options()).unwrap();
+    let mut labels = summarize(registry.push("call".into(), head));
+    let json = serde_json::to_string(&registry.snapshot()).unwrap();
+    drop(registry);
+
+    let mut registry = VadRegistry::<String, i16>::new(options()).unwrap();
+    registry
+        .restore(serde_json::from_str(&json).unwrap())
+        .unwrap();
+    labels.extend(summarize(registry.push("call".into(), tail)));
+    labels.extend(summarize(registry.finish(&"call".into()).unwrap()));
+
+    assert_eq!(expected, labels);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn registry_restore_rejects_mismatched_snapshot() {
+    let mut registry = VadRegistry::<String, i16>::new(options()).unwrap();
+    registry.push("call".into(), &samples()[..1000]);
+    let snapshot = registry.snapshot();
+
+    let larger = RegistryOptions::builder()
+        .sample_rate(8000)
+        .chunk_size(512usize)
+        .build();
+    let mut other = VadRegistry::<String, i16>::new(larger).unwrap();
+    assert!(matches!(
+        other.restore(snapshot.clone()),
+        Err(Error::SnapshotError { .. })
+    ));
+    assert!(other.is_empty());
+
+    // Malformed states are rejected rather than silently reset.
+    let json = serde_json::to_value(&snapshot).unwrap();
+    for (field, value) in [
+        ("pending", serde_json::json!(vec![0; 256])),
+        ("h", serde_json::json!([0.0])),
+    ] {
+        let mut json = json.clone();
+        json["streams"][0][1][field] = value;
+        let mut registry = VadRegistry::<String, i16>::new(options()).unwrap();
+        assert!(matches!(
+            registry.restore(serde_json::from_value(json).unwrap()),
+            Err(Error::SnapshotError { .. })
+        ));
+        assert!(registry.is_empty());
+    }
+}
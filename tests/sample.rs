@@ -0,0 +1,20 @@
+use voice_activity_detector::Sample;
+
+#[test]
+fn from_f32_inverts_to_f32() {
+    for sample in [i16::MIN, -1000, -1, 0, 1, 1000, i16::MAX] {
+        assert_eq!(sample, i16::from_f32(sample.to_f32()));
+    }
+    for sample in [0u8, 1, 128, u8::MAX] {
+        assert_eq!(sample, u8::from_f32(sample.to_f32()));
+    }
+    assert_eq!(0.25, f32::from_f32(0.25f32.to_f32()));
+}
+
+#[test]
+fn from_f32_clamps() {
+    assert_eq!(i16::MAX, i16::from_f32(2.0));
+    assert_eq!(i16::MIN, i16::from_f32(-2.0));
+    assert_eq!(0, u16::from_f32(-0.5));
+    assert_eq!(i8::MAX, i8::from_f32(1.0));
+}
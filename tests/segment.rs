@@ -0,0 +1,21 @@
+use voice_activity_detector::{LabeledAudio, Segmenter, SpeechSegment};
+
+#[test]
+fn segmenter_discards_short_segments() {
+    let labels = [
+        LabeledAudio::Speech(vec![0i16; 400]),
+        LabeledAudio::NonSpeech(vec![0i16; 1000]),
+        LabeledAudio::Speech(vec![0i16; 1500]),
+        LabeledAudio::NonSpeech(vec![0i16; 1000]),
+        LabeledAudio::Speech(vec![0i16; 200]),
+    ];
+
+    let mut segmenter = Segmenter::new(1000).with_min_duration(0.5);
+    let mut segments: Vec<SpeechSegment> = labels
+        .iter()
+        .filter_map(|audio| segmenter.push(audio))
+        .collect();
+    segments.extend(segmenter.finish());
+
+    assert_eq!(vec![SpeechSegment::new(1.4, 2.9)], segments);
+}
@@ -0,0 +1,137 @@
+use voice_activity_detector::{IteratorExt, LabeledAudio, SpeechStats};
+
+fn speech(len: usize) -> LabeledAudio<i16> {
+    LabeledAudio::Speech(vec![0; len])
+}
+
+fn silence(len: usize) -> LabeledAudio<i16> {
+    LabeledAudio::NonSpeech(vec![0; len])
+}
+
+fn labels() -> Vec<LabeledAudio<i16>> {
+    vec![
+        silence(500),
+        speech(1000),
+        speech(1000),
+        silence(500),
+        speech(1000),
+        silence(1000),
+        silence(1000),
+        speech(3000),
+        silence(1000),
+    ]
+}
+
+#[test]
+fn summary_of_labels() {
+    let mut stats = SpeechStats::new(1000);
+    for audio in labels() {
+        stats.push(&audio);
+    }
+    let summary = stats.summary();
+
+    assert_eq!(6.0, summary.speech_duration);
+    assert_eq!(4.0, summary.non_speech_duration);
+    assert_eq!(0.6, summary.speech_ratio);
+    assert_eq!(3, summary.utterances);
+
+    let utterances = summary.utterance_lengths;
+    assert_eq!(3, utterances.count);
+    assert_eq!(1.0, utterances.min);
+    assert_eq!(3.0, utterances.max);
+    assert_eq!(2.0, utterances.mean);
+    assert_eq!(2.0, utterances.p50);
+    assert_eq!(3.0, utterances.p99);
+
+    // Leading and trailing silence are not pauses.
+    let pauses = summary.pause_lengths;
+    assert_eq!(2, pauses.count);
+    assert_eq!(0.5, pauses.min);
+    assert_eq!(2.0, pauses.max);
+}
+
+#[test]
+fn summary_counts_utterance_in_progress() {
+    let mut stats = SpeechStats::new(1000);
+    stats.push(&speech(500));
+    assert_eq!(1, stats.summary().utterances);
+    assert_eq!(0.5, stats.summary().utterance_lengths.max);
+
+    stats.push(&speech(500));
+    assert_eq!(1, stats.summary().utterances);
+    assert_eq!(1.0, stats.summary().utterance_lengths.max);
+
+    // Silence is only a pause once the next utterance starts.
+    stats.push(&silence(250));
+    assert_eq!(0, stats.summary().pause_lengths.count);
+    stats.push(&speech(250));
+    assert_eq!(2, stats.summary().utterances);
+    assert_eq!(0.25, stats.summary().pause_lengths.max);
+}
+
+#[test]
+fn probability_histogram() {
+    let mut stats = SpeechStats::new(1000);
+    for probability in [0.0, 0.05, 0.5, 0.95, 1.0] {
+        stats.push_probability(probability);
+    }
+    let summary = stats.summary();
+
+    assert_eq!(
+        vec![2, 0, 0, 0, 0, 1, 0, 0, 0, 2],
+        summary.probability_histogram
+    );
+    assert!((summary.mean_probability - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn empty_summary() {
+    let summary = SpeechStats::new(1000).summary();
+
+    assert_eq!(0.0, summary.speech_ratio);
+    assert_eq!(0, summary.utterances);
+    assert_eq!(0.0, summary.mean_probability);
+    assert_eq!(10, summary.probability_histogram.len());
+}
+
+#[test]
+fn reset_clears_stats() {
+    let mut stats = SpeechStats::new(1000);
+    stats.push(&speech(500));
+    stats.push_probability(0.9);
+    stats.reset();
+
+    assert_eq!(SpeechStats::new(1000).summary(), stats.summary());
+}
+
+#[test]
+fn iterator_passes_labels_through() {
+    let mut iter = labels().into_iter().speech_stats(1000);
+
+    let first: Vec<LabeledAudio<i16>> = iter.by_ref().take(3).collect();
+    assert_eq!(3, first.len());
+    assert_eq!(2.0, iter.stats().summary().speech_duration);
+
+    assert_eq!(6, iter.by_ref().count());
+    assert_eq!(3, iter.stats().summary().utterances);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn stream_matches_iterator() {
+    use futures::StreamExt as _;
+    use voice_activity_detector::StreamExt;
+
+    let mut iter = labels().into_iter().speech_stats(1000);
+    iter.by_ref().for_each(drop);
+    let expected = iter.into_stats().summary();
+
+    let mut stream = futures::stream::iter(labels()).speech_stats(1000);
+    let mut count = 0;
+    while stream.next().await.is_some() {
+        count += 1;
+    }
+
+    assert_eq!(labels().len(), count);
+    assert_eq!(expected, stream.into_stats().summary());
+}
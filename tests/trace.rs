@@ -0,0 +1,123 @@
+use voice_activity_detector::{Error, ProbabilityTrace};
+
+/// A trace of 0.1 second chunks with speech from 0.5 to 1.0 seconds.
+fn trace() -> ProbabilityTrace {
+    let mut trace = ProbabilityTrace::new(8000, 800);
+    for probability in [0.1, 0.2, 0.1, 0.05, 0.3, 0.9, 0.8, 0.95, 0.7, 0.6, 0.2, 0.1] {
+        trace.push(probability);
+    }
+    trace
+}
+
+#[test]
+fn csv_round_trip() {
+    let trace = trace();
+    let mut csv = Vec::new();
+    trace.write_csv(&mut csv).unwrap();
+
+    let text = String::from_utf8(csv.clone()).unwrap();
+    assert!(
+        text.starts_with("# sample_rate=8000\n# chunk_size=800\nchunk_index,probability\n0,0.1\n")
+    );
+
+    assert_eq!(trace, ProbabilityTrace::read_csv(csv.as_slice()).unwrap());
+}
+
+#[test]
+fn binary_round_trip() {
+    let trace = trace();
+    let mut bytes = Vec::new();
+    trace.write_binary(&mut bytes).unwrap();
+    assert_eq!(29 + 4 * trace.probabilities.len(), bytes.len());
+
+    assert_eq!(
+        trace,
+        ProbabilityTrace::read_binary(bytes.as_slice()).unwrap()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_round_trip() {
+    let trace = trace();
+    let json = serde_json::to_string(&trace).unwrap();
+    assert_eq!(trace, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn csv_errors() {
+    let missing = "chunk_index,probability\n0,0.5\n";
+    assert!(matches!(
+        ProbabilityTrace::read_csv(missing.as_bytes()),
+        Err(Error::TraceFormatError { .. })
+    ));
+
+    for header in [
+        "# sample_rate=0\n# chunk_size=800\n",
+        "# sample_rate=8000\n# chunk_size=0\n",
+    ] {
+        assert!(matches!(
+            ProbabilityTrace::read_csv(header.as_bytes()),
+            Err(Error::TraceFormatError { .. })
+        ));
+    }
+
+    let skipped = "# sample_rate=8000\n# chunk_size=800\n0,0.5\n2,0.5\n";
+    assert!(matches!(
+        ProbabilityTrace::read_csv(skipped.as_bytes()),
+        Err(Error::ParseError { line: 4, .. })
+    ));
+
+    let invalid = "# sample_rate=8000\n# chunk_size=800\n0,speech\n";
+    assert!(matches!(
+        ProbabilityTrace::read_csv(invalid.as_bytes()),
+        Err(Error::ParseError { line: 3, .. })
+    ));
+}
+
+#[test]
+fn binary_errors() {
+    assert!(matches!(
+        ProbabilityTrace::read_binary(&b"RIFF\x01"[..]),
+        Err(Error::TraceFormatError { .. })
+    ));
+
+    let mut empty = Vec::new();
+    ProbabilityTrace::new(8000, 0)
+        .write_binary(&mut empty)
+        .unwrap();
+    assert!(matches!(
+        ProbabilityTrace::read_binary(empty.as_slice()),
+        Err(Error::TraceFormatError { .. })
+    ));
+
+    let mut truncated = Vec::new();
+    trace().write_binary(&mut truncated).unwrap();
+    truncated.pop();
+    assert!(matches!(
+        ProbabilityTrace::read_binary(truncated.as_slice()),
+        Err(Error::Io(_))
+    ));
+}
+
+#[test]
+fn replay_labels() {
+    let csv = "# sample_rate=8000\n# chunk_size=800\nchunk_index,probability\n\
+               0,0.1\n1,0.2\n2,0.1\n3,0.05\n4,0.3\n5,0.9\n6,0.8\n7,0.95\n8,0.7\n9,0.6\n10,0.2\n11,0.1\n";
+    let trace = ProbabilityTrace::read_csv(csv.as_bytes()).unwrap();
+
+    let speech = |labels: Vec<bool>| {
+        assert_eq!(trace.probabilities.len(), labels.len());
+        (0..labels.len())
+            .filter(|&index| labels[index])
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(vec![5, 6, 7, 8, 9, 10], speech(trace.label(0.5, 0)));
+    assert_eq!(vec![4, 5, 6, 7, 8, 9, 10], speech(trace.label(0.5, 1)));
+    assert_eq!(vec![5, 6, 7, 8], speech(trace.label(0.8, 0)));
+
+    let segments = trace.segments(0.5, 0, 0.0);
+    assert_eq!(1, segments.len());
+    assert!((segments[0].start - 0.5).abs() < 1e-9);
+}
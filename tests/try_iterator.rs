@@ -0,0 +1,75 @@
+use voice_activity_detector::{IteratorExt, LabeledAudio, TryIteratorExt, VoiceActivityDetector};
+
+fn vad() -> VoiceActivityDetector {
+    VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(256usize)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn try_label_wav_samples() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let samples: Vec<i16> = reader.samples::<i16>().collect::<Result<_, _>>()?;
+    let expected: Vec<_> = samples
+        .iter()
+        .copied()
+        .label(vad(), 0.5, 3)
+        .map(|audio| (audio.is_speech(), audio.len()))
+        .collect();
+
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let labels = reader
+        .samples::<i16>()
+        .try_label(vad(), 0.5, 3)
+        .map(|audio| audio.map(|audio| (audio.is_speech(), audio.len())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(expected, labels);
+    Ok(())
+}
+
+#[test]
+fn try_label_passes_errors_through() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let mut samples: Vec<Result<i16, &str>> =
+        reader.samples::<i16>().map(|s| Ok(s.unwrap())).collect();
+    let count = samples.len();
+    samples.insert(count / 2, Err("decode error"));
+
+    let mut errors = 0;
+    let mut labeled = Vec::new();
+    for audio in samples.into_iter().try_label(vad(), 0.5, 3) {
+        match audio {
+            Ok(audio) => labeled.push(audio),
+            Err(error) => {
+                assert_eq!("decode error", error);
+                errors += 1;
+            }
+        }
+    }
+
+    // The error does not drop any buffered audio.
+    assert_eq!(1, errors);
+    let total: usize = labeled.iter().map(LabeledAudio::len).sum();
+    assert_eq!(count - count % 256, total);
+    Ok(())
+}
+
+#[test]
+fn try_predict_stops_at_error() {
+    let chunks = (0..1024)
+        .map(|i| {
+            if i == 300 {
+                Err("decode error")
+            } else {
+                Ok(0i16)
+            }
+        })
+        .try_predict(vad())
+        .map_while(Result::ok)
+        .count();
+
+    assert_eq!(1, chunks);
+}
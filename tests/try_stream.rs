@@ -0,0 +1,35 @@
+#![cfg(feature = "async")]
+
+use futures::StreamExt;
+use voice_activity_detector::{LabeledAudio, TryStreamExt as _, VoiceActivityDetector};
+
+#[tokio::test]
+async fn try_label_stream_passes_errors_through() -> Result<(), Box<dyn std::error::Error>> {
+    let vad = VoiceActivityDetector::builder()
+        .sample_rate(8000)
+        .chunk_size(512usize)
+        .build()?;
+
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let mut samples: Vec<Result<i16, String>> = reader
+        .samples::<i16>()
+        .map(|sample| sample.map_err(|error| error.to_string()))
+        .collect();
+    let count = samples.len();
+    samples.insert(count / 3, Err("decode error".into()));
+
+    let labels: Vec<_> = tokio_stream::iter(samples)
+        .try_label(vad, 0.75, 3)
+        .collect()
+        .await;
+
+    let errors = labels.iter().filter(|audio| audio.is_err()).count();
+    assert_eq!(1, errors);
+    let total: usize = labels
+        .iter()
+        .filter_map(|audio| audio.as_ref().ok())
+        .map(LabeledAudio::len)
+        .sum();
+    assert_eq!(count - count % 512, total);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use voice_activity_detector::{
+    tune, EvalOptions, IteratorExt, ProbabilityTrace, SpeechSegment, TuneGrid, TuneObjective,
+    VoiceActivityDetector,
+};
+
+/// A trace of 0.1 second chunks with speech from 1.0 to 2.0 seconds.
+fn trace() -> ProbabilityTrace {
+    let mut probabilities = vec![0.1; 10];
+    probabilities.extend([0.3, 0.8, 0.9, 0.8, 0.7, 0.9, 0.8, 0.9, 0.8, 0.6]);
+    probabilities.extend(vec![0.1; 10]);
+    ProbabilityTrace {
+        sample_rate: 8000,
+        chunk_size: 800,
+        probabilities,
+    }
+}
+
+#[test]
+fn trace_segments() {
+    let trace = trace();
+    assert!((trace.duration() - 3.0).abs() < 1e-9);
+
+    let segments = trace.segments(0.5, 1, 0.0);
+    assert_eq!(1, segments.len());
+    assert!((segments[0].start - 1.0).abs() < 1e-9);
+    assert!(segments[0].end >= 2.0 && segments[0].end <= 2.3);
+
+    assert!(trace.segments(0.5, 1, 2.0).is_empty());
+    assert!(trace.segments(0.95, 1, 0.0).is_empty());
+}
+
+#[test]
+fn tune_finds_best_threshold() {
+    let files = vec![(trace(), vec![SpeechSegment::new(1.0, 2.0)])];
+    let grid = TuneGrid::builder()
+        .thresholds(vec![0.05, 0.2, 0.5, 0.95])
+        .padding_chunks(vec![0, 5])
+        .min_durations(vec![0.0, 0.5])
+        .build();
+
+    let report = tune(&files, &grid, TuneObjective::F1, &EvalOptions::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(16, report.points.len());
+    assert_eq!(4, report.curve.len());
+    assert_eq!(0, report.best.padding_chunks);
+    assert!(report.best.threshold > 0.05 && report.best.threshold < 0.95);
+    assert!(report.best.report.f1 > 0.9);
+
+    // Lower thresholds never find less speech.
+    for pair in report.curve.windows(2) {
+        assert!(pair[0].true_positive_rate >= pair[1].true_positive_rate);
+        assert!(pair[0].false_alarm_rate >= pair[1].false_alarm_rate);
+    }
+
+    let empty = TuneGrid::builder().thresholds(Vec::new()).build();
+    assert!(
+        tune(&files, &empty, TuneObjective::F1, &EvalOptions::default())
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn trace_matches_label_iterator() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = hound::WavReader::open("tests/samples/sample.wav")?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().map_while(Result::ok).collect();
+
+    let mut vad = VoiceActivityDetector::builder()
+        .chunk_size(256usize)
+        .sample_rate(spec.sample_rate)
+        .build()?;
+
+    let trace = ProbabilityTrace::predict(&mut vad, samples.iter().copied());
+    vad.reset();
+    let labels: Vec<_> = samples.into_iter().label(&mut vad, 0.5, 10).collect();
+
+    let expected = SpeechSegment::from_labels(&labels, spec.sample_rate);
+    assert_eq!(expected, trace.segments(0.5, 10, 0.0));
+
+    Ok(())
+}
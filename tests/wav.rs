@@ -0,0 +1,48 @@
+#![cfg(feature = "wav")]
+
+use voice_activity_detector::{Error, VoiceActivityDetector, WavOptions};
+
+#[test]
+fn label_wav() -> Result<(), Box<dyn std::error::Error>> {
+    let options = WavOptions::builder()
+        .threshold(0.5)
+        .padding_chunks(10)
+        .build();
+    let labels = VoiceActivityDetector::label_wav("tests/samples/sample.wav", &options)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert!(labels.iter().any(|audio| audio.is_speech()));
+
+    Ok(())
+}
+
+#[test]
+fn segments_from_wav() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = "tests/.outputs/wav_segments";
+    std::fs::create_dir_all(dir)?;
+
+    let options = WavOptions::builder()
+        .threshold(0.5)
+        .padding_chunks(10)
+        .segment_dir(dir)
+        .build();
+    let segments = VoiceActivityDetector::segments_from_wav("tests/samples/sample.wav", &options)?;
+
+    assert!(!segments.is_empty());
+    for (i, segment) in segments.iter().enumerate() {
+        assert!(segment.start < segment.end);
+        let reader = hound::WavReader::open(format!("{dir}/sample.{i}.wav"))?;
+        let spec = reader.spec();
+        let duration = f64::from(reader.duration()) / f64::from(spec.sample_rate);
+        assert!((duration - segment.duration()).abs() < 1e-6);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn label_wav_missing_file() {
+    let result =
+        VoiceActivityDetector::label_wav("tests/samples/missing.wav", &WavOptions::default());
+    assert!(matches!(result, Err(Error::Wav(_))));
+}